@@ -0,0 +1,50 @@
+//! Compares [`Initialized::init_systems`] with [`Initialized::init_systems_interned`].
+//!
+//! The `already initialized` case removes [`Schedules`] after the first call to show the fast path never accesses it.
+//!
+//! Run with `cargo bench --bench init_systems`.
+
+use std::hint::black_box;
+
+use bevy::{
+    ecs::schedule::{InternedScheduleLabel, ScheduleLabel},
+    prelude::*,
+};
+use bevy_init_marker::Initialized;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn my_system() {}
+
+fn init_systems(c: &mut Criterion) {
+    let mut world = World::new();
+    world.init_resource::<Schedules>();
+    assert!(Initialized::init_systems(&mut world, Update, my_system));
+    c.bench_function("init_systems", |b| {
+        b.iter(|| Initialized::init_systems(black_box(&mut world), Update, my_system));
+    });
+
+    let mut world = World::new();
+    world.init_resource::<Schedules>();
+    let label: InternedScheduleLabel = Update.intern();
+    Initialized::init_systems_interned(&mut world, label, my_system);
+    c.bench_function("init_systems_interned", |b| {
+        b.iter(|| {
+            Initialized::init_systems_interned(black_box(&mut world), black_box(label), my_system)
+        });
+    });
+
+    let mut world = World::new();
+    world.init_resource::<Schedules>();
+    assert!(Initialized::init_systems(&mut world, Update, my_system));
+    world.remove_resource::<Schedules>();
+    c.bench_function("already initialized", |b| {
+        b.iter(|| Initialized::init_systems(black_box(&mut world), Update, my_system));
+    });
+    assert!(
+        !world.contains_resource::<Schedules>(),
+        "the fast path accessed `Schedules`"
+    );
+}
+
+criterion_group!(benches, init_systems);
+criterion_main!(benches);
@@ -0,0 +1,80 @@
+//! Compares [`Initialized::is_initialized`] with calling [`Initialized::init`] on an initialized marker.
+//!
+//! Both are meant to be called every frame, so they're checked to never allocate before being measured.
+//!
+//! Run with `cargo bench --bench is_initialized_hot`.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    hint::black_box,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use bevy::prelude::*;
+use bevy_init_marker::Initialized;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Counts the number of allocations.
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+// SAFETY: Forwards to the system allocator.
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+const ITERATIONS: u32 = 10_000;
+
+struct MyMarker;
+
+/// Panics if calling `f` allocates, criterion allocates itself so this is checked outside of it.
+fn assert_no_alloc(name: &str, mut f: impl FnMut()) {
+    // warm up
+    f();
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations;
+    assert_eq!(allocations, 0, "`{name}` allocated on the hot path");
+}
+
+fn is_initialized_hot(c: &mut Criterion) {
+    let mut world = World::new();
+    assert_no_alloc("is_initialized (unset)", || {
+        black_box(Initialized::<MyMarker>::is_initialized(black_box(&world)));
+    });
+    c.bench_function("is_initialized (unset)", |b| {
+        b.iter(|| Initialized::<MyMarker>::is_initialized(black_box(&world)));
+    });
+
+    assert!(Initialized::<MyMarker>::init(&mut world));
+    assert_no_alloc("is_initialized", || {
+        black_box(Initialized::<MyMarker>::is_initialized(black_box(&world)));
+    });
+    c.bench_function("is_initialized", |b| {
+        b.iter(|| Initialized::<MyMarker>::is_initialized(black_box(&world)));
+    });
+
+    assert_no_alloc("init (already initialized)", || {
+        black_box(Initialized::<MyMarker>::init(black_box(&mut world)));
+    });
+    c.bench_function("init (already initialized)", |b| {
+        b.iter(|| Initialized::<MyMarker>::init(black_box(&mut world)));
+    });
+}
+
+criterion_group!(benches, is_initialized_hot);
+criterion_main!(benches);
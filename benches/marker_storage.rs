@@ -0,0 +1,71 @@
+//! Compares per-type [`Initialized`] resources with the shared [`InitializedSet`] for many markers.
+//!
+//! Run with `cargo bench --bench marker_storage`.
+
+use std::hint::black_box;
+
+use bevy::prelude::*;
+use bevy_init_marker::{Initialized, InitializedSet};
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+
+/// Defines a marker type for every identifier and a function that initializes all of them.
+macro_rules! markers {
+    ($($marker:ident),*) => {
+        $(struct $marker;)*
+
+        fn init_all(world: &mut World, init: &impl Init) {
+            $(black_box(init.init::<$marker>(world));)*
+        }
+    };
+}
+
+markers!(
+    M00, M01, M02, M03, M04, M05, M06, M07, M08, M09, M10, M11, M12, M13, M14, M15, M16, M17, M18,
+    M19, M20, M21, M22, M23, M24, M25, M26, M27, M28, M29, M30, M31, M32, M33, M34, M35, M36, M37,
+    M38, M39, M40, M41, M42, M43, M44, M45, M46, M47, M48, M49, M50, M51, M52, M53, M54, M55, M56,
+    M57, M58, M59, M60, M61, M62, M63
+);
+
+trait Init {
+    fn init<M: Send + Sync + 'static>(&self, world: &mut World) -> bool;
+}
+
+struct PerType;
+
+impl Init for PerType {
+    fn init<M: Send + Sync + 'static>(&self, world: &mut World) -> bool {
+        Initialized::<M>::init(world)
+    }
+}
+
+struct Shared;
+
+impl Init for Shared {
+    fn init<M: Send + Sync + 'static>(&self, world: &mut World) -> bool {
+        InitializedSet::init::<M>(world)
+    }
+}
+
+fn bench(c: &mut Criterion, name: &str, init: impl Init) {
+    c.bench_function(&format!("{name} first init"), |b| {
+        b.iter_batched_ref(
+            World::new,
+            |world| init_all(world, &init),
+            BatchSize::SmallInput,
+        );
+    });
+
+    let mut world = World::new();
+    init_all(&mut world, &init);
+    c.bench_function(&format!("{name} repeated"), |b| {
+        b.iter(|| init_all(black_box(&mut world), &init));
+    });
+}
+
+fn marker_storage(c: &mut Criterion) {
+    bench(c, "Initialized", PerType);
+    bench(c, "InitializedSet", Shared);
+}
+
+criterion_group!(benches, marker_storage);
+criterion_main!(benches);
@@ -0,0 +1,186 @@
+use std::any::TypeId;
+
+use bevy_ecs::{system::Resource, world::World};
+
+use crate::Initialized;
+
+/// The groups of markers that share their initialization, created by [`Initialized::alias`].
+#[derive(Resource, Default)]
+struct MarkerAliases(Vec<Vec<Alias>>);
+
+impl MarkerAliases {
+    /// Returns the index of the group containing the marker with the `type_id`.
+    fn group(&self, type_id: TypeId) -> Option<usize> {
+        self.0
+            .iter()
+            .position(|group| group.iter().any(|alias| alias.type_id == type_id))
+    }
+}
+
+/// A marker in a group of [`MarkerAliases`].
+#[derive(Clone, Copy)]
+struct Alias {
+    type_id: TypeId,
+    init: fn(&mut World) -> bool,
+    deinit: fn(&mut World) -> bool,
+    is_initialized: fn(&World) -> bool,
+}
+
+impl Alias {
+    fn of<M: Send + Sync + 'static>() -> Self {
+        Self {
+            type_id: TypeId::of::<M>(),
+            init: |world| Initialized::<M>::insert(world, true),
+            deinit: Initialized::<M>::remove,
+            is_initialized: Initialized::<M>::is_initialized,
+        }
+    }
+}
+
+impl Initialized<()> {
+    /// Treats the markers `Old` and `New` as the same initialization.
+    ///
+    /// If either of them is already initialized both are initialized,
+    /// and from then on initializing or deinitializing one of them does the same for the other.
+    /// Aliases are transitive, so aliasing `A` to `B` and `B` to `C` makes all three the same initialization.
+    ///
+    /// Useful when renaming a marker, so that the old and new names share their state during a migration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// struct OldMarker;
+    /// struct NewMarker;
+    ///
+    /// let _ = Initialized::<OldMarker>::init(&mut world);
+    ///
+    /// Initialized::alias::<OldMarker, NewMarker>(&mut world);
+    /// assert!(!Initialized::<NewMarker>::init(&mut world));
+    /// ```
+    pub fn alias<Old: Send + Sync + 'static, New: Send + Sync + 'static>(world: &mut World) {
+        let mut aliases = world.get_resource_or_insert_with(MarkerAliases::default);
+        let group = match (
+            aliases.group(TypeId::of::<Old>()),
+            aliases.group(TypeId::of::<New>()),
+        ) {
+            (Some(old), Some(new)) if old == new => old,
+            (Some(old), Some(new)) => {
+                let merged = aliases.0.swap_remove(new);
+                // `old` moved if it was the last group
+                let old = if old == aliases.0.len() { new } else { old };
+                aliases.0[old].extend(merged);
+                old
+            }
+            (Some(old), None) => {
+                aliases.0[old].push(Alias::of::<New>());
+                old
+            }
+            (None, Some(new)) => {
+                aliases.0[new].push(Alias::of::<Old>());
+                new
+            }
+            (None, None) => {
+                aliases.0.push(vec![Alias::of::<Old>(), Alias::of::<New>()]);
+                aliases.0.len() - 1
+            }
+        };
+        trace!(
+            "Aliased `{}` to `{}`",
+            std::any::type_name::<Old>(),
+            std::any::type_name::<New>()
+        );
+
+        let group = aliases.0[group].clone();
+        if group.iter().any(|alias| (alias.is_initialized)(world)) {
+            for alias in group {
+                (alias.init)(world);
+            }
+        }
+    }
+}
+
+/// Returns the aliases of the marker with the `type_id`, not including itself.
+fn aliases_of(world: &World, type_id: TypeId) -> Vec<Alias> {
+    world
+        .get_resource::<MarkerAliases>()
+        .and_then(|aliases| aliases.group(type_id).map(|group| &aliases.0[group]))
+        .map(|group| {
+            group
+                .iter()
+                .filter(|alias| alias.type_id != type_id)
+                .copied()
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Initializes the aliases of the marker with the `type_id`.
+pub(crate) fn init_aliases(world: &mut World, type_id: TypeId) {
+    for alias in aliases_of(world, type_id) {
+        (alias.init)(world);
+    }
+}
+
+/// Deinitializes the aliases of the marker with the `type_id`.
+pub(crate) fn deinit_aliases(world: &mut World, type_id: TypeId) {
+    for alias in aliases_of(world, type_id) {
+        (alias.deinit)(world);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Initialized;
+    use bevy::prelude::*;
+
+    #[test]
+    fn test_alias() {
+        struct Old;
+        struct New;
+
+        let mut world = World::new();
+        Initialized::alias::<Old, New>(&mut world);
+        assert!(!Initialized::<New>::is_initialized(&world));
+
+        assert!(Initialized::<New>::init(&mut world));
+        assert!(Initialized::<Old>::is_initialized(&world));
+        assert!(!Initialized::<Old>::init(&mut world));
+
+        assert!(Initialized::<Old>::deinit(&mut world));
+        assert!(!Initialized::<New>::is_initialized(&world));
+    }
+
+    #[test]
+    fn test_alias_initialized() {
+        struct Old;
+        struct New;
+
+        let mut world = World::new();
+        assert!(Initialized::<Old>::init(&mut world));
+        Initialized::alias::<Old, New>(&mut world);
+        assert!(!Initialized::<New>::init(&mut world));
+    }
+
+    #[test]
+    fn test_alias_transitive() {
+        struct A;
+        struct B;
+        struct C;
+        struct D;
+
+        let mut world = World::new();
+        Initialized::alias::<A, B>(&mut world);
+        Initialized::alias::<C, D>(&mut world);
+        Initialized::alias::<B, C>(&mut world);
+
+        assert!(Initialized::<D>::init(&mut world));
+        assert!(Initialized::<A>::is_initialized(&world));
+        assert!(Initialized::<B>::is_initialized(&world));
+        assert!(Initialized::<C>::is_initialized(&world));
+    }
+}
@@ -0,0 +1,747 @@
+#[cfg(feature = "reflect")]
+use std::any::TypeId;
+
+use bevy_app::{App, AppLabel, MainScheduleOrder, Plugin, Startup};
+#[cfg(feature = "reflect")]
+use bevy_ecs::reflect::AppTypeRegistry;
+use bevy_ecs::{
+    schedule::{IntoSystemConfigs, ScheduleLabel},
+    world::World,
+};
+#[cfg(feature = "reflect")]
+use bevy_reflect::TypePath;
+
+use crate::Initialized;
+
+/// Extension trait for [`App`] that forwards to the functions on [`Initialized`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_init_marker::InitMarkerAppExt;
+/// # use bevy::prelude::*;
+/// #
+/// # let mut app = App::new();
+/// #
+/// struct MyMarker;
+///
+/// fn my_system() {
+///     // do stuff
+/// }
+///
+/// app.init_once::<MyMarker>()
+///     .init_systems_once(Update, my_system);
+///
+/// if app.try_init_systems_once(Update, my_system) {
+///     unreachable!("my_system has already been initialized in Update");
+/// }
+/// ```
+pub trait InitMarkerAppExt {
+    /// Initializes the `Initialized<M>` resource if it hasn't been initialized yet.
+    ///
+    /// See [`Initialized::init`].
+    #[track_caller]
+    fn init_once<M: Send + Sync + 'static>(&mut self) -> &mut Self;
+
+    /// Initializes the `Initialized<M>` resource if it hasn't been initialized yet.
+    ///
+    /// Returns `true` if the resource was not previously initialized, `false` otherwise.
+    ///
+    /// See [`Initialized::init`].
+    #[must_use]
+    #[track_caller]
+    fn try_init_once<M: Send + Sync + 'static>(&mut self) -> bool;
+
+    /// Initialize the `systems` if they hasn't been initialized for the `schedule` yet.
+    ///
+    /// See [`Initialized::init_systems`].
+    fn init_systems_once<L, S, Marker>(&mut self, schedule: L, systems: S) -> &mut Self
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static;
+
+    /// Initialize the `systems` if they hasn't been initialized for the `schedule` yet.
+    ///
+    /// Returns `true` if the systems were added, `false` otherwise.
+    ///
+    /// See [`Initialized::init_systems`].
+    fn try_init_systems_once<L, S, Marker>(&mut self, schedule: L, systems: S) -> bool
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static;
+
+    /// Adds the `systems` to [`Startup`] if they hasn't been added to it yet.
+    ///
+    /// [`Startup`] only runs once per app, but the plugin code registering the systems may run more than once,
+    /// in which case the systems would run several times during that single [`Startup`].
+    ///
+    /// The dedup is per app, every new app runs the systems again.
+    ///
+    /// See [`Initialized::init_systems`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::InitMarkerAppExt;
+    /// # use bevy::prelude::*;
+    /// #
+    /// fn setup() {
+    ///     // do stuff once at startup
+    /// }
+    ///
+    /// fn my_plugin(app: &mut App) {
+    ///     app.add_startup_systems_once(setup);
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins(my_plugin);
+    ///
+    /// // `setup` still runs only once
+    /// my_plugin(&mut app);
+    /// ```
+    fn add_startup_systems_once<S, Marker>(&mut self, systems: S) -> &mut Self
+    where
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static;
+}
+
+impl InitMarkerAppExt for App {
+    #[track_caller]
+    fn init_once<M: Send + Sync + 'static>(&mut self) -> &mut Self {
+        let _ = Initialized::<M>::init(&mut self.world);
+        self
+    }
+
+    #[track_caller]
+    fn try_init_once<M: Send + Sync + 'static>(&mut self) -> bool {
+        Initialized::<M>::init(&mut self.world)
+    }
+
+    #[track_caller]
+    fn init_systems_once<L, S, Marker>(&mut self, schedule: L, systems: S) -> &mut Self
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        Initialized::ensure_systems(&mut self.world, schedule, systems);
+        self
+    }
+
+    #[track_caller]
+    fn try_init_systems_once<L, S, Marker>(&mut self, schedule: L, systems: S) -> bool
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        Initialized::init_systems(&mut self.world, schedule, systems)
+    }
+
+    #[track_caller]
+    fn add_startup_systems_once<S, Marker>(&mut self, systems: S) -> &mut Self
+    where
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        self.init_systems_once(Startup, systems)
+    }
+}
+
+#[cfg(feature = "reflect")]
+impl<M: Send + Sync + TypePath> Initialized<M> {
+    /// Registers `Initialized<M>` with the [`AppTypeRegistry`](bevy_ecs::reflect::AppTypeRegistry).
+    ///
+    /// This makes the marker show up in inspectors and reflection-driven serialization.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut app = App::new();
+    /// #
+    /// #[derive(TypePath)]
+    /// struct MyMarker;
+    ///
+    /// Initialized::<MyMarker>::register_type(&mut app);
+    /// ```
+    pub fn register_type(app: &mut App) {
+        app.register_type::<Self>();
+    }
+
+    /// Returns `true` if `Initialized<M>` is registered with the [`AppTypeRegistry`](bevy_ecs::reflect::AppTypeRegistry) of the `world`.
+    ///
+    /// Only registered markers are carried over by reflection, like when saving the resources of a world
+    /// to a [`DynamicScene`](https://docs.rs/bevy/0.13/bevy/scene/struct.DynamicScene.html) and writing it to another world.
+    /// Unregistered markers are silently left out, so the markers would be initialized again in the new world.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut app = App::new();
+    /// #
+    /// #[derive(TypePath)]
+    /// struct MyMarker;
+    ///
+    /// assert!(!Initialized::<MyMarker>::is_reflected(&app.world));
+    ///
+    /// Initialized::<MyMarker>::register_type(&mut app);
+    /// assert!(Initialized::<MyMarker>::is_reflected(&app.world));
+    /// ```
+    #[must_use]
+    pub fn is_reflected(world: &World) -> bool {
+        world
+            .get_resource::<AppTypeRegistry>()
+            .is_some_and(|registry| registry.read().get(TypeId::of::<Self>()).is_some())
+    }
+
+    /// Registers `Initialized<M>` with the [`AppTypeRegistry`](bevy_ecs::reflect::AppTypeRegistry) and initializes it.
+    ///
+    /// Returns `true` if the resource was not previously initialized, `false` otherwise.
+    ///
+    /// See [`Initialized::register_type`] and [`Initialized::init`].
+    #[must_use]
+    #[track_caller]
+    pub fn init_and_register(app: &mut App) -> bool {
+        Self::register_type(app);
+        Self::init(&mut app.world)
+    }
+}
+
+impl<M: Send + Sync + 'static> Initialized<M> {
+    /// Initializes the `Initialized<M>` resource and runs `f` with the `app` if it hasn't been initialized yet.
+    ///
+    /// Returns `true` if `f` was run, `false` otherwise.
+    ///
+    /// Meant to be called from [`Plugin::finish`], which runs after every plugin has been built,
+    /// so markers initialized in [`Plugin::build`] are already visible.
+    ///
+    /// See [`Initialized::init_on_cleanup`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// struct MyPlugin;
+    ///
+    /// struct MyMarker;
+    ///
+    /// impl Plugin for MyPlugin {
+    ///     fn build(&self, app: &mut App) {
+    ///         // do stuff
+    ///     }
+    ///
+    ///     fn finish(&self, app: &mut App) {
+    ///         Initialized::<MyMarker>::init_on_finish(app, |app| {
+    ///             // do stuff once, after every plugin has been built
+    ///         });
+    ///     }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn init_on_finish(app: &mut App, f: impl FnOnce(&mut App)) -> bool {
+        Self::init_app(app, f)
+    }
+
+    /// Initializes the `Initialized<M>` resource and runs `f` with the `app` if it hasn't been initialized yet.
+    ///
+    /// Returns `true` if `f` was run, `false` otherwise.
+    ///
+    /// Meant to be called from [`Plugin::cleanup`], which runs after [`Plugin::finish`] for every plugin,
+    /// so markers initialized in [`Plugin::build`] and [`Plugin::finish`] are already visible.
+    ///
+    /// See [`Initialized::init_on_finish`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// struct MyPlugin;
+    ///
+    /// struct MyMarker;
+    ///
+    /// impl Plugin for MyPlugin {
+    ///     fn build(&self, app: &mut App) {
+    ///         // do stuff
+    ///     }
+    ///
+    ///     fn cleanup(&self, app: &mut App) {
+    ///         Initialized::<MyMarker>::init_on_cleanup(app, |app| {
+    ///             // do stuff once, after every plugin has finished
+    ///         });
+    ///     }
+    /// }
+    /// ```
+    #[track_caller]
+    pub fn init_on_cleanup(app: &mut App, f: impl FnOnce(&mut App)) -> bool {
+        Self::init_app(app, f)
+    }
+
+    /// Same as [`Initialized::init_with`] but `f` gets the whole `app`.
+    #[track_caller]
+    fn init_app(app: &mut App, f: impl FnOnce(&mut App)) -> bool {
+        if Self::init(&mut app.world) {
+            f(app);
+            return true;
+        }
+        false
+    }
+}
+
+impl Initialized<()> {
+    /// Initialize the `systems` for the `schedule` of the [`SubApp`](bevy_app::SubApp) with the given `label`.
+    ///
+    /// The marker lives in the world of the sub app, so the same `systems` can be initialized once in each app.
+    /// This is the same as calling [`Initialized::init_systems`] with `&mut app.sub_app_mut(label).world`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sub app with the given `label` does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::{app::{AppLabel, SubApp}, prelude::*};
+    /// #
+    /// # let mut app = App::new();
+    /// #
+    /// #[derive(AppLabel, Debug, Clone, PartialEq, Eq, Hash)]
+    /// struct MySubApp;
+    ///
+    /// app.insert_sub_app(MySubApp, SubApp::new(App::empty(), |_, _| {}));
+    ///
+    /// fn my_system() {
+    ///     // do stuff
+    /// }
+    ///
+    /// assert!(Initialized::init_systems_in_sub_app(&mut app, MySubApp, Update, my_system));
+    /// assert!(!Initialized::init_systems_in_sub_app(&mut app, MySubApp, Update, my_system));
+    ///
+    /// // the main app has its own marker
+    /// assert!(Initialized::init_systems(&mut app.world, Update, my_system));
+    /// ```
+    #[track_caller]
+    pub fn init_systems_in_sub_app<L, S, Marker>(
+        app: &mut App,
+        label: impl AppLabel,
+        schedule: L,
+        systems: S,
+    ) -> bool
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        let sub_app = app.sub_app_mut(label);
+        Initialized::init_systems(&mut sub_app.world, schedule, systems)
+    }
+
+    /// Initialize the `systems` for the `schedule` and run the `schedule` after `after` in the [`Main`](bevy_app::Main) schedule.
+    ///
+    /// Returns `true` if the systems were added, `false` otherwise.
+    ///
+    /// A schedule created by [`Initialized::init_systems`] never runs unless it's in the [`MainScheduleOrder`],
+    /// this inserts the `schedule` into the order if it isn't in it yet, even if the systems were already added.
+    /// The `schedule` is never inserted twice, even for different systems or if it's already in the order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`MainScheduleOrder`] resource does not exist in the `world`, which [`App::new`] inserts.
+    ///
+    /// Panics if `after` is not in the [`MainScheduleOrder`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+    /// #
+    /// # let mut app = App::new();
+    /// #
+    /// #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+    /// struct LateUpdate;
+    ///
+    /// fn my_system() {
+    ///     // runs after `Update`
+    /// }
+    ///
+    /// assert!(Initialized::init_ordered_systems(&mut app.world, LateUpdate, Update, my_system));
+    /// assert!(!Initialized::init_ordered_systems(&mut app.world, LateUpdate, Update, my_system));
+    /// ```
+    #[track_caller]
+    pub fn init_ordered_systems<L, A, S, Marker>(
+        world: &mut World,
+        schedule: L,
+        after: A,
+        systems: S,
+    ) -> bool
+    where
+        L: ScheduleLabel,
+        A: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        assert!(
+            world.contains_resource::<MainScheduleOrder>(),
+            "`MainScheduleOrder` resource does not exist in the world"
+        );
+        let label = schedule.intern();
+        let added = Initialized::init_systems_unchecked(world, label, systems);
+        let mut order = world.resource_mut::<MainScheduleOrder>();
+        if !order.labels.contains(&label) {
+            trace!(
+                "Inserted `{:?}` into the main schedule order after `{:?}`",
+                label,
+                after
+            );
+            order.insert_after(after, label);
+        }
+        added
+    }
+
+    /// Adds the `plugin` to the `app` if `Initialized<P>` hasn't been initialized yet.
+    ///
+    /// Returns `true` if the plugin was added, `false` otherwise.
+    ///
+    /// Unlike [`App::is_plugin_added`] this only considers plugins added through this method,
+    /// adding `P` by other means won't prevent it from being added again.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut app = App::new();
+    /// #
+    /// struct MyPlugin;
+    ///
+    /// impl Plugin for MyPlugin {
+    ///     fn build(&self, app: &mut App) {
+    ///         // do stuff
+    ///     }
+    /// }
+    ///
+    /// assert!(Initialized::init_plugin(&mut app, MyPlugin));
+    /// assert!(!Initialized::init_plugin(&mut app, MyPlugin));
+    /// ```
+    #[track_caller]
+    pub fn init_plugin<P: Plugin>(app: &mut App, plugin: P) -> bool {
+        if Initialized::<P>::init(&mut app.world) {
+            app.add_plugins(plugin);
+            return true;
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{InitMarkerAppExt, Initialized};
+    use bevy::{
+        app::{AppLabel, MainScheduleOrder, SubApp},
+        ecs::schedule::{InternedScheduleLabel, ScheduleLabel},
+        prelude::*,
+    };
+
+    #[test]
+    fn test_app_init_once() {
+        struct MyMarker;
+
+        let mut app = App::new();
+        app.init_once::<MyMarker>();
+        assert!(!app.try_init_once::<MyMarker>());
+    }
+
+    #[test]
+    fn test_app_init_systems_once() {
+        fn sys1() {}
+        fn sys2() {}
+
+        let mut app = App::new();
+        app.init_systems_once(Update, sys1)
+            .init_systems_once(Update, sys1);
+        assert!(!app.try_init_systems_once(Update, sys1));
+        assert!(app.try_init_systems_once(Update, sys2));
+    }
+
+    #[test]
+    fn test_add_startup_systems_once() {
+        #[derive(Resource, Default)]
+        struct Counter(usize);
+
+        fn count(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        fn my_plugin(app: &mut App) {
+            app.add_startup_systems_once(count);
+        }
+
+        for _ in 0..2 {
+            let mut app = App::new();
+            app.init_resource::<Counter>();
+            my_plugin(&mut app);
+            my_plugin(&mut app);
+
+            app.update();
+            app.update();
+            assert_eq!(app.world.resource::<Counter>().0, 1);
+        }
+    }
+
+    #[test]
+    fn test_init_ordered_systems() {
+        #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+        struct LateUpdate;
+
+        #[derive(Resource, Default)]
+        struct Order(Vec<&'static str>);
+
+        fn update(mut order: ResMut<Order>) {
+            order.0.push("update");
+        }
+
+        fn late_update(mut order: ResMut<Order>) {
+            order.0.push("late_update");
+        }
+
+        fn other() {}
+
+        let mut app = App::new();
+        app.init_resource::<Order>().add_systems(Update, update);
+
+        assert!(Initialized::init_ordered_systems(
+            &mut app.world,
+            LateUpdate,
+            Update,
+            late_update
+        ));
+        assert!(!Initialized::init_ordered_systems(
+            &mut app.world,
+            LateUpdate,
+            Update,
+            late_update
+        ));
+        assert!(Initialized::init_ordered_systems(
+            &mut app.world,
+            LateUpdate,
+            PostUpdate,
+            other
+        ));
+
+        let order = app.world.resource::<MainScheduleOrder>();
+        let position =
+            |label: InternedScheduleLabel| order.labels.iter().position(|l| *l == label).unwrap();
+        assert_eq!(
+            order
+                .labels
+                .iter()
+                .filter(|l| **l == LateUpdate.intern())
+                .count(),
+            1
+        );
+        assert_eq!(position(LateUpdate.intern()), position(Update.intern()) + 1);
+
+        app.update();
+        assert_eq!(app.world.resource::<Order>().0, ["update", "late_update"]);
+    }
+
+    #[test]
+    fn test_init_ordered_systems_existing() {
+        #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+        struct LateUpdate;
+
+        #[derive(Resource, Default)]
+        struct Counter(usize);
+
+        fn count(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        let mut app = App::new();
+        app.init_resource::<Counter>();
+
+        // the systems were added without ordering the schedule
+        assert!(Initialized::init_systems_unchecked(
+            &mut app.world,
+            LateUpdate,
+            count
+        ));
+        assert!(!Initialized::init_ordered_systems(
+            &mut app.world,
+            LateUpdate,
+            Update,
+            count
+        ));
+        assert!(app
+            .world
+            .resource::<MainScheduleOrder>()
+            .labels
+            .contains(&LateUpdate.intern()));
+
+        app.update();
+        assert_eq!(app.world.resource::<Counter>().0, 1);
+    }
+
+    #[test]
+    #[should_panic = "`MainScheduleOrder` resource does not exist in the world"]
+    fn test_init_ordered_systems_missing_order() {
+        #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+        struct LateUpdate;
+
+        let mut world = World::new();
+        let _ = Initialized::init_ordered_systems(&mut world, LateUpdate, Update, || {});
+    }
+
+    #[test]
+    fn test_init_systems_in_sub_app() {
+        #[derive(AppLabel, Debug, Clone, PartialEq, Eq, Hash)]
+        struct MySubApp;
+
+        fn sys1() {}
+
+        let mut app = App::new();
+        app.insert_sub_app(MySubApp, SubApp::new(App::empty(), |_, _| {}));
+
+        assert!(Initialized::init_systems_in_sub_app(
+            &mut app, MySubApp, Update, sys1
+        ));
+        assert!(!Initialized::init_systems_in_sub_app(
+            &mut app, MySubApp, Update, sys1
+        ));
+        assert!(app
+            .sub_app(MySubApp)
+            .world
+            .resource::<Schedules>()
+            .contains(Update));
+        assert!(app.try_init_systems_once(Update, sys1));
+    }
+
+    #[test]
+    fn test_init_plugin() {
+        struct MyPlugin;
+
+        impl Plugin for MyPlugin {
+            fn build(&self, app: &mut App) {
+                app.add_systems(Update, || {});
+            }
+        }
+
+        let mut app = App::new();
+        assert!(Initialized::init_plugin(&mut app, MyPlugin));
+        assert!(!Initialized::init_plugin(&mut app, MyPlugin));
+        assert!(app.is_plugin_added::<MyPlugin>());
+    }
+
+    #[cfg(feature = "reflect")]
+    #[test]
+    fn test_scene_round_trip() {
+        use bevy::{
+            ecs::entity::EntityHashMap,
+            scene::{serde::SceneDeserializer, DynamicSceneBuilder},
+        };
+        use serde::de::DeserializeSeed;
+
+        #[derive(TypePath)]
+        struct Reflected;
+
+        #[derive(TypePath)]
+        struct NotReflected;
+
+        let mut app = App::new();
+        assert!(Initialized::<Reflected>::init_and_register(&mut app));
+        assert!(Initialized::<NotReflected>::init(&mut app.world));
+        assert!(Initialized::<Reflected>::is_reflected(&app.world));
+        assert!(!Initialized::<NotReflected>::is_reflected(&app.world));
+
+        let registry = app.world.resource::<AppTypeRegistry>().clone();
+        let scene = DynamicSceneBuilder::from_world(&app.world)
+            .extract_resources()
+            .build();
+        let ron = scene.serialize_ron(&registry.0).unwrap();
+
+        let mut deserializer = ron::de::Deserializer::from_str(&ron).unwrap();
+        let scene = SceneDeserializer {
+            type_registry: &registry.read(),
+        }
+        .deserialize(&mut deserializer)
+        .unwrap();
+
+        let mut world = World::new();
+        world.insert_resource(registry);
+        scene
+            .write_to_world(&mut world, &mut EntityHashMap::default())
+            .unwrap();
+
+        // only the registered marker is carried over
+        assert!(Initialized::<Reflected>::is_initialized(&world));
+        assert!(!Initialized::<NotReflected>::is_initialized(&world));
+    }
+
+    #[cfg(feature = "reflect")]
+    #[test]
+    fn test_register_type() {
+        #[derive(TypePath)]
+        struct MyMarker;
+
+        let mut app = App::new();
+        assert!(Initialized::<MyMarker>::init_and_register(&mut app));
+        assert!(!Initialized::<MyMarker>::init_and_register(&mut app));
+
+        let registry = app.world.resource::<AppTypeRegistry>().read();
+        let registration = registry
+            .get(std::any::TypeId::of::<Initialized<MyMarker>>())
+            .unwrap();
+        assert!(registration.data::<ReflectResource>().is_some());
+    }
+
+    #[test]
+    fn test_init_on_finish_and_cleanup() {
+        #[derive(Resource, Default)]
+        struct Counter(usize);
+
+        struct BuildMarker;
+        struct FinishMarker;
+        struct CleanupMarker;
+
+        struct MyPlugin;
+
+        impl Plugin for MyPlugin {
+            fn build(&self, app: &mut App) {
+                app.init_resource::<Counter>();
+                assert!(Initialized::<BuildMarker>::init(&mut app.world));
+            }
+
+            fn finish(&self, app: &mut App) {
+                // markers from `build` are respected
+                assert!(!Initialized::<BuildMarker>::init_on_finish(app, |_| {
+                    unreachable!()
+                }));
+                Initialized::<FinishMarker>::init_on_finish(app, |app| {
+                    app.world.resource_mut::<Counter>().0 += 1;
+                });
+            }
+
+            fn cleanup(&self, app: &mut App) {
+                assert!(Initialized::<FinishMarker>::is_initialized(&app.world));
+                Initialized::<CleanupMarker>::init_on_cleanup(app, |app| {
+                    app.world.resource_mut::<Counter>().0 += 1;
+                });
+            }
+        }
+
+        let mut app = App::new();
+        app.add_plugins(MyPlugin);
+        for _ in 0..2 {
+            app.finish();
+            app.cleanup();
+        }
+        assert_eq!(app.world.resource::<Counter>().0, 2);
+        assert!(Initialized::<CleanupMarker>::is_initialized(&app.world));
+    }
+}
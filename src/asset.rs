@@ -0,0 +1,155 @@
+use bevy_asset::{Asset, AssetServer, Assets, Handle, LoadState};
+use bevy_ecs::{
+    schedule::{IntoSystemConfigs, ScheduleLabel},
+    world::World,
+};
+
+use crate::Initialized;
+
+impl Initialized<()> {
+    /// Initialize the `systems` for the `schedule` once the asset of the `handle` is loaded.
+    ///
+    /// Returns `true` if the systems were added by this call, `false` otherwise.
+    ///
+    /// Until the asset is loaded nothing is initialized, so calling this again every frame keeps retrying.
+    /// The asset is loaded if the [`AssetServer`] reports [`LoadState::Loaded`] for the `handle`,
+    /// or if it's in [`Assets<A>`] for handles the [`AssetServer`] doesn't know about.
+    ///
+    /// See [`Initialized::init_systems`] and [`Initialized::init_if`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// # use bevy::reflect::TypePath;
+    /// # use bevy_asset::{Asset, Assets, Handle, UntypedAssetId, VisitAssetDependencies};
+    /// #
+    /// # let mut world = World::new();
+    /// # world.init_resource::<Schedules>();
+    /// #
+    /// struct Config;
+    /// #
+    /// # impl TypePath for Config {
+    /// #     fn type_path() -> &'static str {
+    /// #         "my_game::Config"
+    /// #     }
+    /// #
+    /// #     fn short_type_path() -> &'static str {
+    /// #         "Config"
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Asset for Config {}
+    /// #
+    /// # impl VisitAssetDependencies for Config {
+    /// #     fn visit_dependencies(&self, _visit: &mut impl FnMut(UntypedAssetId)) {}
+    /// # }
+    ///
+    /// fn my_system() {
+    ///     // uses the config
+    /// }
+    ///
+    /// # world.init_resource::<Assets<Config>>();
+    /// let handle: Handle<Config> = world.resource::<Assets<Config>>().reserve_handle();
+    ///
+    /// // waits for the config to be loaded
+    /// assert!(!Initialized::init_when_loaded(&mut world, &handle, Update, my_system));
+    ///
+    /// world.resource_mut::<Assets<Config>>().insert(&handle, Config);
+    /// assert!(Initialized::init_when_loaded(&mut world, &handle, Update, my_system));
+    /// assert!(!Initialized::init_when_loaded(&mut world, &handle, Update, my_system));
+    /// ```
+    #[track_caller]
+    pub fn init_when_loaded<A, L, S, Marker>(
+        world: &mut World,
+        handle: &Handle<A>,
+        schedule: L,
+        systems: S,
+    ) -> bool
+    where
+        A: Asset,
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        if !is_loaded(world, handle) {
+            return false;
+        }
+        Initialized::init_systems(world, schedule, systems)
+    }
+}
+
+/// Returns `true` if the asset of the `handle` is loaded.
+fn is_loaded<A: Asset>(world: &World, handle: &Handle<A>) -> bool {
+    if let Some(state) = world
+        .get_resource::<AssetServer>()
+        .and_then(|server| server.get_load_state(handle))
+    {
+        return state == LoadState::Loaded;
+    }
+    world
+        .get_resource::<Assets<A>>()
+        .is_some_and(|assets| assets.contains(handle))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Initialized;
+    use bevy::{prelude::*, reflect::TypePath};
+    use bevy_asset::{Asset, Assets, UntypedAssetId, VisitAssetDependencies};
+
+    struct StubAsset;
+
+    impl TypePath for StubAsset {
+        fn type_path() -> &'static str {
+            "bevy_init_marker::asset::tests::StubAsset"
+        }
+
+        fn short_type_path() -> &'static str {
+            "StubAsset"
+        }
+    }
+
+    impl Asset for StubAsset {}
+
+    impl VisitAssetDependencies for StubAsset {
+        fn visit_dependencies(&self, _visit: &mut impl FnMut(UntypedAssetId)) {}
+    }
+
+    #[derive(Resource, Default)]
+    struct Counter(usize);
+
+    fn count(mut counter: ResMut<Counter>) {
+        counter.0 += 1;
+    }
+
+    #[test]
+    fn test_init_when_loaded() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.init_resource::<Assets<StubAsset>>();
+        let handle = world.resource::<Assets<StubAsset>>().reserve_handle();
+
+        // loading
+        for _ in 0..3 {
+            assert!(!Initialized::init_when_loaded(
+                &mut world, &handle, Update, count
+            ));
+        }
+        assert!(!world.contains_resource::<Schedules>());
+
+        // loaded
+        world
+            .resource_mut::<Assets<StubAsset>>()
+            .insert(&handle, StubAsset);
+        assert!(Initialized::init_when_loaded(
+            &mut world, &handle, Update, count
+        ));
+        assert!(!Initialized::init_when_loaded(
+            &mut world, &handle, Update, count
+        ));
+
+        world.run_schedule(Update);
+        assert_eq!(world.resource::<Counter>().0, 1);
+    }
+}
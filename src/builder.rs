@@ -0,0 +1,213 @@
+use std::{any::TypeId, fmt::Debug, marker::PhantomData};
+
+use bevy_ecs::{
+    schedule::{InternedScheduleLabel, IntoSystemConfigs, ScheduleLabel, SystemConfigs},
+    world::World,
+};
+
+use crate::{add_systems, remove_systems_anywhere, InitRegistry, Initialized};
+
+/// A builder for initializing the marker `M` with non-default options.
+///
+/// Created by [`Initialized::builder`], the simple [`Initialized::init`] and [`Initialized::init_systems`]
+/// functions should be preferred for the common case.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_init_marker::Initialized;
+/// # use bevy::prelude::*;
+/// #
+/// # let mut world = World::new();
+/// #
+/// struct MyPlugin;
+///
+/// fn my_system() {
+///     // do stuff
+/// }
+///
+/// let initialized = Initialized::<MyPlugin>::builder()
+///     .emit_event(false)
+///     .schedule(PostUpdate)
+///     .systems(my_system)
+///     .apply(&mut world);
+/// assert!(initialized);
+/// ```
+#[must_use]
+pub struct InitBuilder<M: Send + Sync + 'static> {
+    #[cfg(feature = "log")]
+    log_level: bevy_log::Level,
+    emit_event: bool,
+    schedule: Option<InternedScheduleLabel>,
+    systems: Option<SystemConfigs>,
+    _marker: PhantomData<fn() -> M>,
+}
+
+impl<M: Send + Sync + 'static> Debug for InitBuilder<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug = f.debug_struct(&format!("InitBuilder<{}>", std::any::type_name::<M>()));
+        #[cfg(feature = "log")]
+        debug.field("log_level", &self.log_level);
+        debug
+            .field("emit_event", &self.emit_event)
+            .field("schedule", &self.schedule)
+            .field("systems", &self.systems.is_some())
+            .finish()
+    }
+}
+
+impl<M: Send + Sync + 'static> Default for InitBuilder<M> {
+    fn default() -> Self {
+        Self {
+            #[cfg(feature = "log")]
+            log_level: bevy_log::Level::TRACE,
+            emit_event: true,
+            schedule: None,
+            systems: None,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<M: Send + Sync + 'static> InitBuilder<M> {
+    /// Sets the level the initialization is logged at, defaults to `TRACE`.
+    ///
+    /// See [`Initialized::init_log`].
+    #[cfg(feature = "log")]
+    pub fn log_level(mut self, level: bevy_log::Level) -> Self {
+        self.log_level = level;
+        self
+    }
+
+    /// Sets whether a [`MarkerInitialized`](crate::MarkerInitialized) event is sent when the events feature is enabled, defaults to `true`.
+    pub fn emit_event(mut self, emit_event: bool) -> Self {
+        self.emit_event = emit_event;
+        self
+    }
+
+    /// Sets the schedule the [`systems`](InitBuilder::systems) are added to, defaults to [`Update`](bevy_app::Update).
+    pub fn schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = Some(schedule.intern());
+        self
+    }
+
+    /// Sets the `systems` that are added to the [`schedule`](InitBuilder::schedule) when the marker is initialized.
+    pub fn systems<Marker>(mut self, systems: impl IntoSystemConfigs<Marker>) -> Self {
+        self.systems = Some(systems.into_configs());
+        self
+    }
+
+    /// Initializes the `Initialized<M>` resource and adds the systems if it hasn't been initialized yet.
+    ///
+    /// Returns `true` if the resource was not previously initialized, `false` otherwise.
+    #[track_caller]
+    pub fn apply(self, world: &mut World) -> bool {
+        if !Initialized::<M>::init_inner(world, self.emit_event) {
+            return false;
+        }
+        #[cfg(feature = "log")]
+        Initialized::<M>::log_initialized(self.log_level);
+
+        if let Some(systems) = self.systems {
+            let schedule = self.schedule.unwrap_or_else(|| bevy_app::Update.intern());
+            add_systems(world, TypeId::of::<Self>(), schedule, systems);
+            InitRegistry::set_deinit::<M>(world, deinit_with_systems::<M>);
+        }
+        true
+    }
+}
+
+/// Deinitializes the marker `M` and disables the systems added by [`InitBuilder::apply`],
+/// used by [`Initialized::clear_all`] and [`Initialized::clear_matching`].
+fn deinit_with_systems<M: Send + Sync + 'static>(world: &mut World) -> bool {
+    remove_systems_anywhere(world, TypeId::of::<InitBuilder<M>>());
+    Initialized::<M>::deinit(world)
+}
+
+impl<M: Send + Sync + 'static> Initialized<M> {
+    /// Returns an [`InitBuilder`] for initializing the marker `M` with non-default options.
+    pub fn builder() -> InitBuilder<M> {
+        InitBuilder::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Initialized;
+    use bevy::prelude::*;
+
+    #[derive(Resource, Default)]
+    struct Counter(usize);
+
+    fn count(mut counter: ResMut<Counter>) {
+        counter.0 += 1;
+    }
+
+    #[test]
+    fn test_builder() {
+        struct MyMarker;
+
+        let mut app = App::new();
+        app.init_resource::<Counter>();
+
+        for _ in 0..3 {
+            Initialized::<MyMarker>::builder()
+                .schedule(PostUpdate)
+                .systems(count)
+                .apply(&mut app.world);
+        }
+        assert!(Initialized::<MyMarker>::is_initialized(&app.world));
+
+        app.update();
+        assert_eq!(app.world.resource::<Counter>().0, 1);
+    }
+
+    #[test]
+    fn test_builder_clear_all() {
+        struct MyMarker;
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+
+        for _ in 0..2 {
+            assert!(Initialized::<MyMarker>::builder()
+                .systems(count)
+                .apply(&mut world));
+            assert_eq!(Initialized::clear_all(&mut world), 1);
+        }
+        assert!(Initialized::<MyMarker>::builder()
+            .systems(count)
+            .apply(&mut world));
+
+        world.run_schedule(Update);
+        assert_eq!(world.resource::<Counter>().0, 1);
+    }
+
+    #[test]
+    fn test_builder_default_schedule() {
+        struct MyMarker;
+
+        let mut world = World::new();
+        assert!(Initialized::<MyMarker>::builder()
+            .systems(|| {})
+            .apply(&mut world));
+        assert!(world.resource::<Schedules>().contains(Update));
+    }
+
+    #[cfg(feature = "events")]
+    #[test]
+    fn test_builder_emit_event() {
+        use crate::MarkerInitialized;
+
+        struct MarkerA;
+        struct MarkerB;
+
+        let mut world = World::new();
+        world.init_resource::<Events<MarkerInitialized>>();
+        assert!(Initialized::<MarkerA>::builder()
+            .emit_event(false)
+            .apply(&mut world));
+        assert!(Initialized::<MarkerB>::builder().apply(&mut world));
+        assert_eq!(world.resource::<Events<MarkerInitialized>>().len(), 1);
+    }
+}
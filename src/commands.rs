@@ -0,0 +1,210 @@
+use std::panic::Location;
+
+use bevy_ecs::{
+    schedule::{IntoSystemConfigs, ScheduleLabel},
+    system::Commands,
+    world::World,
+};
+
+use crate::{InitRegistry, Initialized, PendingInits};
+
+/// Extension trait for [`Commands`] that defers the functions on [`Initialized`] until the commands are applied.
+///
+/// Commands are applied one at a time with exclusive access to the world,
+/// so if several systems running in parallel queue the same marker only the first applied command initializes it.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_init_marker::InitMarkerCommandsExt;
+/// # use bevy::prelude::*;
+/// #
+/// # let mut app = App::new();
+/// #
+/// struct MyMarker;
+///
+/// fn my_system() {
+///     // do stuff
+/// }
+///
+/// fn setup(mut commands: Commands) {
+///     commands
+///         .init_once::<MyMarker>()
+///         .init_systems_once(Update, my_system);
+/// }
+///
+/// app.add_systems(Update, setup);
+/// ```
+pub trait InitMarkerCommandsExt {
+    /// Initializes the `Initialized<M>` resource if it hasn't been initialized yet.
+    ///
+    /// See [`Initialized::init`].
+    fn init_once<M: Send + Sync + 'static>(&mut self) -> &mut Self;
+
+    /// Initializes the `Initialized<M>` resource if it hasn't been initialized yet and runs `f` if it wasn't.
+    ///
+    /// See [`Initialized::init`].
+    fn init_once_then<M: Send + Sync + 'static>(
+        &mut self,
+        f: impl FnOnce(&mut World) + Send + 'static,
+    ) -> &mut Self;
+
+    /// Initialize the `systems` if they hasn't been initialized for the `schedule` yet.
+    ///
+    /// See [`Initialized::init_systems`].
+    fn init_systems_once<L, S, Marker>(&mut self, schedule: L, systems: S) -> &mut Self
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static;
+
+    /// Queues the `setup` in [`PendingInits`] to run once [`apply_pending_inits`](crate::apply_pending_inits) runs.
+    ///
+    /// Nothing is queued if `Initialized<M>` is already initialized or a setup for `M` is already queued,
+    /// so the `setup` runs at most once per marker.
+    fn init_pending<M: Send + Sync + 'static>(
+        &mut self,
+        setup: impl FnOnce(&mut World) + Send + 'static,
+    ) -> &mut Self;
+}
+
+impl InitMarkerCommandsExt for Commands<'_, '_> {
+    #[track_caller]
+    fn init_once<M: Send + Sync + 'static>(&mut self) -> &mut Self {
+        let location = Location::caller();
+        self.add(move |world: &mut World| {
+            if Initialized::<M>::init(world) {
+                InitRegistry::relocate::<M>(world, location);
+            }
+        });
+        self
+    }
+
+    #[track_caller]
+    fn init_once_then<M: Send + Sync + 'static>(
+        &mut self,
+        f: impl FnOnce(&mut World) + Send + 'static,
+    ) -> &mut Self {
+        let location = Location::caller();
+        self.add(move |world: &mut World| {
+            if Initialized::<M>::init(world) {
+                InitRegistry::relocate::<M>(world, location);
+                f(world);
+            }
+        });
+        self
+    }
+
+    #[track_caller]
+    fn init_systems_once<L, S, Marker>(&mut self, schedule: L, systems: S) -> &mut Self
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        self.add(move |world: &mut World| {
+            Initialized::ensure_systems(world, schedule, systems);
+        });
+        self
+    }
+
+    #[track_caller]
+    fn init_pending<M: Send + Sync + 'static>(
+        &mut self,
+        setup: impl FnOnce(&mut World) + Send + 'static,
+    ) -> &mut Self {
+        self.add(|world: &mut World| {
+            if !Initialized::<M>::is_initialized(world) {
+                world
+                    .get_resource_or_insert_with(PendingInits::default)
+                    .push::<M>(setup);
+            }
+        });
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{InitMarkerCommandsExt, Initialized};
+    use bevy::{ecs::system::RunSystemOnce, prelude::*};
+
+    #[derive(Resource, Default)]
+    struct Counter(usize);
+
+    #[test]
+    fn test_commands_init_once() {
+        struct MyMarker;
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+
+        for _ in 0..2 {
+            world.run_system_once(|mut commands: Commands| {
+                commands
+                    .init_once_then::<MyMarker>(|world| world.resource_mut::<Counter>().0 += 1)
+                    .init_once_then::<MyMarker>(|world| world.resource_mut::<Counter>().0 += 1);
+            });
+        }
+        assert!(Initialized::<MyMarker>::is_initialized(&world));
+        assert_eq!(world.resource::<Counter>().0, 1);
+    }
+
+    #[test]
+    fn test_commands_init_systems_once() {
+        fn count(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+
+        for _ in 0..2 {
+            world.run_system_once(|mut commands: Commands| {
+                commands.init_systems_once(Update, count);
+            });
+        }
+        world.run_schedule(Update);
+        assert_eq!(world.resource::<Counter>().0, 1);
+    }
+
+    #[test]
+    fn test_commands_init_once_parallel() {
+        struct MyMarker;
+
+        fn init(mut commands: Commands) {
+            commands
+                .init_once_then::<MyMarker>(|world| world.resource_mut::<Counter>().0 += 1)
+                .init_once::<MyMarker>();
+        }
+
+        let mut app = App::new();
+        app.init_resource::<Counter>()
+            .add_systems(Update, (init, init, init));
+        #[cfg(feature = "events")]
+        app.add_event::<crate::MarkerInitialized>();
+
+        app.update();
+        assert_eq!(app.world.resource::<Counter>().0, 1);
+        #[cfg(feature = "events")]
+        assert_eq!(
+            app.world
+                .resource::<Events<crate::MarkerInitialized>>()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_commands_init_location() {
+        struct MyMarker;
+
+        let mut world = World::new();
+        let line = line!() + 2;
+        world.run_system_once(|mut commands: Commands| {
+            commands.init_once::<MyMarker>();
+        });
+
+        let location = Initialized::<MyMarker>::init_location(&world).unwrap();
+        assert_eq!(location.file(), file!());
+        assert_eq!(location.line(), line);
+    }
+}
@@ -0,0 +1,227 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+#[cfg(feature = "reflect")]
+use bevy_ecs::reflect::ReflectComponent;
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    system::EntityCommands,
+    world::{EntityWorldMut, World},
+};
+#[cfg(feature = "reflect")]
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+
+/// A marker [`Component`] for *something* that has been initialized for an entity.
+///
+/// This is the per-entity counterpart to [`Initialized`](crate::Initialized),
+/// see [`InitMarkerEntityWorldMutExt`] and [`InitMarkerEntityCommandsExt`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_init_marker::{InitializedComponent, InitMarkerEntityWorldMutExt};
+/// # use bevy::prelude::*;
+/// #
+/// # let mut world = World::new();
+/// #
+/// struct Outline;
+///
+/// let mut entity = world.spawn_empty();
+/// if entity.init_marker::<Outline>() {
+///     // set up the outline once for this entity
+/// }
+///
+/// assert!(entity.contains::<InitializedComponent<Outline>>());
+/// ```
+#[derive(Component)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Component, Default))]
+pub struct InitializedComponent<M: Send + Sync + 'static>(
+    #[cfg_attr(feature = "reflect", reflect(ignore))] PhantomData<M>,
+);
+
+impl<M: Send + Sync + 'static> Debug for InitializedComponent<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "InitializedComponent<{}>", std::any::type_name::<M>())
+    }
+}
+
+impl<M: Send + Sync + 'static> Clone for InitializedComponent<M> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M: Send + Sync + 'static> Copy for InitializedComponent<M> {}
+
+impl<M: Send + Sync + 'static> Default for InitializedComponent<M> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Extension trait for [`EntityWorldMut`] that initializes [`InitializedComponent`] markers.
+pub trait InitMarkerEntityWorldMutExt {
+    /// Inserts the `InitializedComponent<M>` component if the entity doesn't have it yet.
+    ///
+    /// Returns `true` if the component was not previously on the entity, `false` otherwise.
+    #[must_use]
+    fn init_marker<M: Send + Sync + 'static>(&mut self) -> bool;
+
+    /// Removes the `InitializedComponent<M>` component so that the next [`init_marker`](Self::init_marker) returns `true` again.
+    ///
+    /// Returns `true` if the component was previously on the entity, `false` otherwise.
+    fn deinit_marker<M: Send + Sync + 'static>(&mut self) -> bool;
+
+    /// Returns `true` if the entity has the `InitializedComponent<M>` component.
+    #[must_use]
+    fn is_marker_initialized<M: Send + Sync + 'static>(&self) -> bool;
+}
+
+impl InitMarkerEntityWorldMutExt for EntityWorldMut<'_> {
+    fn init_marker<M: Send + Sync + 'static>(&mut self) -> bool {
+        if self.contains::<InitializedComponent<M>>() {
+            return false;
+        }
+        self.insert(InitializedComponent::<M>::default());
+        trace!(
+            "Initialized `{}` for {:?}",
+            std::any::type_name::<M>(),
+            self.id()
+        );
+        true
+    }
+
+    fn deinit_marker<M: Send + Sync + 'static>(&mut self) -> bool {
+        self.take::<InitializedComponent<M>>().is_some()
+    }
+
+    fn is_marker_initialized<M: Send + Sync + 'static>(&self) -> bool {
+        self.contains::<InitializedComponent<M>>()
+    }
+}
+
+/// Extension trait for [`EntityCommands`] that defers [`InitMarkerEntityWorldMutExt`] until the commands are applied.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_init_marker::InitMarkerEntityCommandsExt;
+/// # use bevy::prelude::*;
+/// #
+/// struct Outline;
+///
+/// fn add_outlines(mut commands: Commands, query: Query<Entity>) {
+///     for entity in &query {
+///         commands.entity(entity).init_marker_then::<Outline>(|entity| {
+///             // set up the outline once for this entity
+///         });
+///     }
+/// }
+/// ```
+pub trait InitMarkerEntityCommandsExt {
+    /// Inserts the `InitializedComponent<M>` component if the entity doesn't have it yet.
+    ///
+    /// See [`InitMarkerEntityWorldMutExt::init_marker`].
+    fn init_marker<M: Send + Sync + 'static>(&mut self) -> &mut Self;
+
+    /// Inserts the `InitializedComponent<M>` component and runs `f` if the entity doesn't have it yet.
+    ///
+    /// See [`InitMarkerEntityWorldMutExt::init_marker`].
+    fn init_marker_then<M: Send + Sync + 'static>(
+        &mut self,
+        f: impl FnOnce(EntityWorldMut) + Send + 'static,
+    ) -> &mut Self;
+}
+
+impl InitMarkerEntityCommandsExt for EntityCommands<'_> {
+    fn init_marker<M: Send + Sync + 'static>(&mut self) -> &mut Self {
+        self.add(|mut entity: EntityWorldMut| {
+            let _ = entity.init_marker::<M>();
+        })
+    }
+
+    fn init_marker_then<M: Send + Sync + 'static>(
+        &mut self,
+        f: impl FnOnce(EntityWorldMut) + Send + 'static,
+    ) -> &mut Self {
+        self.add(|mut entity: EntityWorldMut| {
+            if entity.init_marker::<M>() {
+                f(entity);
+            }
+        })
+    }
+}
+
+impl<M: Send + Sync + 'static> InitializedComponent<M> {
+    /// Returns `true` if the `entity` has the `InitializedComponent<M>` component.
+    ///
+    /// Returns `false` if the `entity` doesn't exist.
+    #[must_use]
+    pub fn is_initialized(world: &World, entity: Entity) -> bool {
+        world
+            .get_entity(entity)
+            .is_some_and(|entity| entity.contains::<Self>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{InitMarkerEntityCommandsExt, InitMarkerEntityWorldMutExt, InitializedComponent};
+    use bevy::{ecs::system::RunSystemOnce, prelude::*};
+
+    #[test]
+    fn test_entity_init_marker() {
+        struct MyMarker;
+
+        let mut world = World::new();
+        let mut entity = world.spawn_empty();
+        assert!(entity.init_marker::<MyMarker>());
+        assert!(!entity.init_marker::<MyMarker>());
+        assert!(entity.is_marker_initialized::<MyMarker>());
+
+        assert!(entity.deinit_marker::<MyMarker>());
+        assert!(!entity.deinit_marker::<MyMarker>());
+        assert!(entity.init_marker::<MyMarker>());
+        let id = entity.id();
+
+        // other entities are independent
+        let other = world.spawn_empty().id();
+        assert!(InitializedComponent::<MyMarker>::is_initialized(&world, id));
+        assert!(!InitializedComponent::<MyMarker>::is_initialized(
+            &world, other
+        ));
+    }
+
+    #[test]
+    fn test_entity_commands_init_marker() {
+        struct MyMarker;
+
+        #[derive(Component, Default)]
+        struct Counter(usize);
+
+        let mut world = World::new();
+        let a = world.spawn(Counter::default()).id();
+        let b = world.spawn(Counter::default()).id();
+
+        for _ in 0..3 {
+            world.run_system_once(|mut commands: Commands, query: Query<Entity>| {
+                for entity in &query {
+                    commands
+                        .entity(entity)
+                        .init_marker_then::<MyMarker>(|mut entity| {
+                            entity.get_mut::<Counter>().unwrap().0 += 1;
+                        });
+                }
+            });
+        }
+        for entity in [a, b] {
+            assert_eq!(world.get::<Counter>(entity).unwrap().0, 1);
+        }
+
+        let c = world.spawn_empty().id();
+        world.run_system_once(move |mut commands: Commands| {
+            commands.entity(c).init_marker::<MyMarker>();
+        });
+        assert!(InitializedComponent::<MyMarker>::is_initialized(&world, c));
+    }
+}
@@ -0,0 +1,292 @@
+use std::{any::TypeId, borrow::Cow, marker::PhantomData, sync::Mutex};
+
+use bevy_ecs::{
+    archetype::ArchetypeComponentId,
+    component::{ComponentId, Tick},
+    query::Access,
+    schedule::{Condition, InternedSystemSet, IntoSystemConfigs, SystemConfigs},
+    system::{Commands, IntoSystem, ReadOnlySystem, Res, Resource, System},
+    world::{unsafe_world_cell::UnsafeWorldCell, World},
+};
+use bevy_utils::HashSet;
+
+use crate::{InitMarkerCommandsExt, Initialized};
+
+/// The markers claimed by [`run_once`] conditions.
+///
+/// Run conditions can't insert resources so [`run_once`] records the marker here instead of inserting [`Initialized`].
+/// [`InitOnce`](crate::InitOnce) claims the marker here until its commands are applied.
+#[derive(Resource, Default)]
+pub struct RunOnceClaims(Mutex<HashSet<TypeId>>);
+
+impl RunOnceClaims {
+    /// Returns `true` if the `marker` was not previously claimed.
+    pub(crate) fn claim(&self, marker: TypeId) -> bool {
+        self.0.lock().unwrap().insert(marker)
+    }
+
+    /// Removes the claim for the `marker`.
+    pub(crate) fn unclaim(&self, marker: TypeId) {
+        self.0.lock().unwrap().remove(&marker);
+    }
+
+    /// Removes the claim for the `marker`, returns `true` if it was claimed.
+    pub(crate) fn take(&self, marker: TypeId) -> bool {
+        self.0.lock().unwrap().remove(&marker)
+    }
+
+    /// Removes every claim.
+    pub(crate) fn clear(&self) {
+        self.0.lock().unwrap().clear();
+    }
+
+    /// Returns `true` if the `marker` is claimed.
+    pub(crate) fn is_claimed(&self, marker: TypeId) -> bool {
+        self.0.lock().unwrap().contains(&marker)
+    }
+}
+
+/// A run condition that returns `true` exactly once for the marker `M`.
+///
+/// Unlike bevy's `run_once` this is keyed on the marker type rather than the condition instance,
+/// so systems using the same marker share a single run, even if they are added again later.
+///
+/// The condition never returns `true` if [`Initialized<M>`] has already been initialized.
+///
+/// Run conditions can't insert resources, so firing only claims the marker and
+/// [`Initialized::<M>::is_initialized`](Initialized::is_initialized) stays `false` until the resource is inserted.
+/// [`InitMarkerSystemsExt::once`] inserts it right after the systems run. Otherwise the next
+/// [`Initialized::<M>::init`](Initialized::init) inserts it and returns `false`, since the one-time run already happened.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_init_marker::run_once;
+/// # use bevy::prelude::*;
+/// #
+/// # let mut app = App::new();
+/// #
+/// struct MyMarker;
+///
+/// fn my_system() {
+///     // runs once
+/// }
+///
+/// app.add_systems(Update, my_system.run_if(run_once::<MyMarker>()));
+/// ```
+pub fn run_once<M: Send + Sync + 'static>() -> impl Condition<()> {
+    RunOnce::<M> {
+        system: Box::new(IntoSystem::into_system(
+            |initialized: Option<Res<Initialized<M>>>, claims: Res<RunOnceClaims>| {
+                initialized.is_none() && claims.claim(TypeId::of::<M>())
+            },
+        )),
+        _marker: PhantomData,
+    }
+}
+
+/// Removes the claim of a [`run_once`] condition for the marker `M`, returns `true` if it was claimed.
+pub(crate) fn take_claim<M: 'static>(world: &World) -> bool {
+    world
+        .get_resource::<RunOnceClaims>()
+        .is_some_and(|claims| claims.take(TypeId::of::<M>()))
+}
+
+/// Extension trait for [`IntoSystemConfigs`] that limits the systems to a single run.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_init_marker::InitMarkerSystemsExt;
+/// # use bevy::prelude::*;
+/// #
+/// # let mut app = App::new();
+/// #
+/// struct MyMarker;
+///
+/// fn my_system() {
+///     // runs once
+/// }
+///
+/// app.add_systems(Update, my_system.once::<MyMarker>());
+/// ```
+pub trait InitMarkerSystemsExt<Marker>: IntoSystemConfigs<Marker> + Sized {
+    /// Runs the systems exactly once for the marker `M`, then initializes [`Initialized<M>`].
+    ///
+    /// The systems are guarded by [`run_once`] and followed by a system that initializes the marker,
+    /// so they never run if the marker was already initialized by other means.
+    fn once<M: Send + Sync + 'static>(self) -> SystemConfigs {
+        (self, init_marker::<M>).chain().run_if(run_once::<M>())
+    }
+}
+
+impl<Marker, T: IntoSystemConfigs<Marker>> InitMarkerSystemsExt<Marker> for T {}
+
+/// Initializes [`Initialized<M>`] once the commands are applied.
+fn init_marker<M: Send + Sync + 'static>(mut commands: Commands) {
+    commands.init_once::<M>();
+}
+
+/// The [`System`] returned by [`run_once`].
+///
+/// Inserts [`RunOnceClaims`] when initialized, otherwise forwards to the inner system.
+struct RunOnce<M> {
+    system: Box<dyn ReadOnlySystem<In = (), Out = bool>>,
+    _marker: PhantomData<fn() -> M>,
+}
+
+impl<M: Send + Sync + 'static> System for RunOnce<M> {
+    type In = ();
+    type Out = bool;
+
+    fn name(&self) -> Cow<'static, str> {
+        format!("run_once<{}>", std::any::type_name::<M>()).into()
+    }
+
+    fn component_access(&self) -> &Access<ComponentId> {
+        self.system.component_access()
+    }
+
+    fn archetype_component_access(&self) -> &Access<ArchetypeComponentId> {
+        self.system.archetype_component_access()
+    }
+
+    fn is_send(&self) -> bool {
+        self.system.is_send()
+    }
+
+    fn is_exclusive(&self) -> bool {
+        self.system.is_exclusive()
+    }
+
+    fn has_deferred(&self) -> bool {
+        self.system.has_deferred()
+    }
+
+    unsafe fn run_unsafe(&mut self, input: Self::In, world: UnsafeWorldCell) -> Self::Out {
+        // SAFETY: `system.run_unsafe` has the same invariants as `self.run_unsafe`.
+        self.system.run_unsafe(input, world)
+    }
+
+    fn run(&mut self, input: Self::In, world: &mut World) -> Self::Out {
+        self.system.run(input, world)
+    }
+
+    fn apply_deferred(&mut self, world: &mut World) {
+        self.system.apply_deferred(world);
+    }
+
+    fn initialize(&mut self, world: &mut World) {
+        world.init_resource::<RunOnceClaims>();
+        self.system.initialize(world);
+    }
+
+    fn update_archetype_component_access(&mut self, world: UnsafeWorldCell) {
+        self.system.update_archetype_component_access(world);
+    }
+
+    fn check_change_tick(&mut self, change_tick: Tick) {
+        self.system.check_change_tick(change_tick);
+    }
+
+    fn default_system_sets(&self) -> Vec<InternedSystemSet> {
+        self.system.default_system_sets()
+    }
+
+    fn get_last_run(&self) -> Tick {
+        self.system.get_last_run()
+    }
+
+    fn set_last_run(&mut self, last_run: Tick) {
+        self.system.set_last_run(last_run);
+    }
+}
+
+// SAFETY: The inner system is read-only.
+unsafe impl<M: Send + Sync + 'static> ReadOnlySystem for RunOnce<M> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{run_once, InitMarkerSystemsExt, Initialized};
+    use bevy::prelude::*;
+
+    #[derive(Resource, Default)]
+    struct Counter(usize);
+
+    fn count(mut counter: ResMut<Counter>) {
+        counter.0 += 1;
+    }
+
+    #[test]
+    fn test_run_once() {
+        struct MyMarker;
+
+        let mut app = App::new();
+        app.init_resource::<Counter>()
+            .add_systems(Update, count.run_if(run_once::<MyMarker>()));
+
+        for _ in 0..10 {
+            app.update();
+        }
+        assert_eq!(app.world.resource::<Counter>().0, 1);
+
+        // adding the system again doesn't run it again
+        app.add_systems(Update, count.run_if(run_once::<MyMarker>()));
+        for _ in 0..10 {
+            app.update();
+        }
+        assert_eq!(app.world.resource::<Counter>().0, 1);
+    }
+
+    #[test]
+    fn test_run_once_initialized() {
+        struct MyMarker;
+
+        let mut app = App::new();
+        app.init_resource::<Counter>()
+            .add_systems(Update, count.run_if(run_once::<MyMarker>()));
+        assert!(Initialized::<MyMarker>::init(&mut app.world));
+
+        app.update();
+        assert_eq!(app.world.resource::<Counter>().0, 0);
+    }
+
+    #[test]
+    fn test_run_once_then_init() {
+        struct MyMarker;
+
+        let mut app = App::new();
+        app.init_resource::<Counter>()
+            .add_systems(Update, count.run_if(run_once::<MyMarker>()));
+
+        app.update();
+        assert_eq!(app.world.resource::<Counter>().0, 1);
+        // the condition only claimed the marker
+        assert!(!Initialized::<MyMarker>::is_initialized(&app.world));
+
+        // the one-time run already happened
+        assert!(!Initialized::<MyMarker>::init(&mut app.world));
+        assert!(Initialized::<MyMarker>::is_initialized(&app.world));
+        app.update();
+        assert_eq!(app.world.resource::<Counter>().0, 1);
+
+        // deinitializing starts over
+        assert!(Initialized::<MyMarker>::deinit(&mut app.world));
+        assert!(Initialized::<MyMarker>::init(&mut app.world));
+    }
+
+    #[test]
+    fn test_once() {
+        struct MyMarker;
+
+        let mut app = App::new();
+        app.init_resource::<Counter>()
+            .add_systems(Update, (count, count).once::<MyMarker>());
+
+        for _ in 0..10 {
+            app.update();
+        }
+        assert_eq!(app.world.resource::<Counter>().0, 2);
+        assert!(Initialized::<MyMarker>::is_initialized(&app.world));
+    }
+}
@@ -0,0 +1,128 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::{
+    schedule::{InternedScheduleLabel, IntoSystemConfigs, IntoSystemSetConfigs, ScheduleLabel},
+    world::World,
+};
+
+use crate::Initialized;
+
+/// Systems or system sets that can be initialized with [`Initialized::init_configs`].
+///
+/// Implemented for everything implementing [`IntoSystemConfigs`] or [`IntoSystemSetConfigs`].
+pub trait IntoInitConfigs<Marker>: Send + Sync + 'static {
+    /// Initializes the configs for the `schedule`, returns `true` if they were not previously initialized.
+    #[doc(hidden)]
+    fn init_configs(self, world: &mut World, schedule: InternedScheduleLabel) -> bool;
+}
+
+/// The `Marker` of [`IntoInitConfigs`] for systems.
+pub struct SystemConfigsMarker<M>(PhantomData<M>);
+
+/// The `Marker` of [`IntoInitConfigs`] for system sets.
+pub struct SetConfigsMarker;
+
+impl<S, M> IntoInitConfigs<SystemConfigsMarker<M>> for S
+where
+    S: IntoSystemConfigs<M> + Send + Sync + 'static,
+{
+    #[track_caller]
+    fn init_configs(self, world: &mut World, schedule: InternedScheduleLabel) -> bool {
+        Initialized::init_systems_interned(world, schedule, self)
+    }
+}
+
+impl<S> IntoInitConfigs<SetConfigsMarker> for S
+where
+    S: IntoSystemSetConfigs + Send + Sync + 'static,
+{
+    #[track_caller]
+    fn init_configs(self, world: &mut World, schedule: InternedScheduleLabel) -> bool {
+        Initialized::init_sets(world, schedule, self)
+    }
+}
+
+impl Initialized<()> {
+    /// Initialize the `configs` if they hasn't been initialized for the `schedule` yet.
+    ///
+    /// The `configs` can be either systems, like [`Initialized::init_systems`], or system sets, like [`Initialized::init_sets`].
+    /// Systems and sets are initialized with different markers, so they never deduplicate each other.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    /// struct MySet;
+    ///
+    /// fn my_system() {
+    ///     // do stuff
+    /// }
+    ///
+    /// assert!(Initialized::init_configs(&mut world, Update, MySet.run_if(|| true)));
+    /// assert!(Initialized::init_configs(&mut world, Update, my_system.in_set(MySet)));
+    ///
+    /// assert!(!Initialized::init_configs(&mut world, Update, MySet.run_if(|| true)));
+    /// ```
+    #[track_caller]
+    pub fn init_configs<L, C, Marker>(world: &mut World, schedule: L, configs: C) -> bool
+    where
+        L: ScheduleLabel,
+        C: IntoInitConfigs<Marker>,
+    {
+        configs.init_configs(world, schedule.intern())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Initialized;
+    use bevy::prelude::*;
+
+    #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    enum MySet {
+        A,
+        B,
+    }
+
+    #[derive(Resource, Default)]
+    struct Order(Vec<&'static str>);
+
+    #[test]
+    fn test_init_configs() {
+        fn a(mut order: ResMut<Order>) {
+            order.0.push("a");
+        }
+
+        fn b(mut order: ResMut<Order>) {
+            order.0.push("b");
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        for _ in 0..2 {
+            Initialized::init_configs(&mut world, Update, MySet::B.before(MySet::A));
+            Initialized::init_configs(&mut world, Update, (a.in_set(MySet::A), b.in_set(MySet::B)));
+        }
+        assert!(!Initialized::init_configs(
+            &mut world,
+            Update,
+            MySet::B.before(MySet::A)
+        ));
+
+        world.run_schedule(Update);
+        assert_eq!(world.resource::<Order>().0, ["b", "a"]);
+
+        // the same sets can be configured in another schedule
+        assert!(Initialized::init_configs(
+            &mut world,
+            PostUpdate,
+            MySet::B.before(MySet::A)
+        ));
+    }
+}
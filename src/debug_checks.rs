@@ -0,0 +1,132 @@
+use std::any::TypeId;
+
+use bevy_ecs::{
+    schedule::{InternedScheduleLabel, NodeId, Schedules},
+    system::Resource,
+    world::World,
+};
+use bevy_utils::HashSet;
+
+use crate::{is_closure, system_type_ids};
+
+/// A [`Resource`] that configures what [`Initialized::init_systems`](crate::Initialized::init_systems) does
+/// when it adds a system that already exists in the schedule.
+///
+/// This catches the quirk where a system added with `add_systems` is added again by `init_systems`.
+/// Closures have unique types so they are never reported.
+///
+/// Defaults to [`DuplicateSystemCheck::Warn`] if the resource does not exist.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_init_marker::DuplicateSystemCheck;
+/// # use bevy::prelude::*;
+/// #
+/// # let mut world = World::new();
+/// #
+/// world.insert_resource(DuplicateSystemCheck::Panic);
+/// ```
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicateSystemCheck {
+    /// Don't check for duplicate systems.
+    Ignore,
+    /// Log a warning for every duplicate system.
+    #[default]
+    Warn,
+    /// Panic on the first duplicate system.
+    Panic,
+}
+
+/// Returns the type ids of the systems in the `schedule`, or `None` if the check is disabled.
+pub(crate) fn existing_systems(
+    world: &World,
+    schedule: InternedScheduleLabel,
+) -> Option<HashSet<TypeId>> {
+    if check(world) == DuplicateSystemCheck::Ignore {
+        return None;
+    }
+    Some(
+        world
+            .get_resource::<Schedules>()
+            .and_then(|schedules| schedules.get(schedule))
+            .map(system_type_ids)
+            .unwrap_or_default(),
+    )
+}
+
+/// Reports the `added` systems whose type is in the `existing` systems of the `schedule`.
+#[track_caller]
+pub(crate) fn check_duplicates(
+    world: &World,
+    schedule: InternedScheduleLabel,
+    existing: Option<HashSet<TypeId>>,
+    added: &[NodeId],
+) {
+    let Some(existing) = existing else {
+        return;
+    };
+    let Some(graph) = world
+        .get_resource::<Schedules>()
+        .and_then(|schedules| schedules.get(schedule))
+        .map(|schedule| schedule.graph())
+    else {
+        return;
+    };
+
+    let duplicates = added
+        .iter()
+        .filter_map(|id| graph.get_system_at(*id))
+        .filter(|system| !is_closure(*system) && existing.contains(&system.type_id()));
+    for system in duplicates {
+        match check(world) {
+            DuplicateSystemCheck::Ignore => {}
+            DuplicateSystemCheck::Warn => bevy_log::warn!(
+                "`{}` appears to already be present in `{:?}`, it will run twice",
+                system.name(),
+                schedule
+            ),
+            DuplicateSystemCheck::Panic => panic!(
+                "`{}` appears to already be present in `{:?}`",
+                system.name(),
+                schedule
+            ),
+        }
+    }
+}
+
+fn check(world: &World) -> DuplicateSystemCheck {
+    world
+        .get_resource::<DuplicateSystemCheck>()
+        .copied()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DuplicateSystemCheck, Initialized};
+    use bevy::prelude::*;
+
+    #[test]
+    #[should_panic = "appears to already be present"]
+    fn test_duplicate_system_check_panic() {
+        fn sys1() {}
+
+        let mut app = App::new();
+        app.insert_resource(DuplicateSystemCheck::Panic)
+            .add_systems(Update, sys1);
+        Initialized::ensure_systems(&mut app.world, Update, sys1);
+    }
+
+    #[test]
+    fn test_duplicate_system_check_ignores_new_systems() {
+        fn sys1() {}
+        fn sys2() {}
+
+        let mut app = App::new();
+        app.insert_resource(DuplicateSystemCheck::Panic)
+            .add_systems(Update, (sys1, || {}));
+        Initialized::ensure_systems(&mut app.world, Update, (sys2, || {}));
+        Initialized::ensure_systems(&mut app.world, PostUpdate, sys1);
+    }
+}
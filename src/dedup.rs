@@ -0,0 +1,223 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::{
+    schedule::{InternedScheduleLabel, IntoSystemConfigs},
+    system::Resource,
+    world::World,
+};
+use bevy_utils::HashSet;
+
+use crate::{add_systems, remove_systems, Initialized, IntoInitConfigs, RegistrationKey};
+
+/// The hashes initialized by [`DedupBy`], keyed on the hash and schedule label.
+#[derive(Resource, Default)]
+struct InitializedHashes(HashSet<(u64, InternedScheduleLabel)>);
+
+/// Systems that are deduplicated by a hash rather than their type, created by [`dedup_by`].
+///
+/// Initialized with [`Initialized::init_configs`](crate::Initialized::init_configs).
+pub struct DedupBy<S> {
+    hash: u64,
+    systems: S,
+}
+
+/// The `Marker` of [`IntoInitConfigs`] for [`DedupBy`].
+pub struct DedupMarker<M>(PhantomData<M>);
+
+/// Wraps the `systems` so they are deduplicated by the `hash` instead of their type.
+///
+/// Different closures have different types, so systems that are generated at runtime are never deduplicated
+/// by [`Initialized::init_systems`](crate::Initialized::init_systems).
+/// With a stable `hash` for the logic of the systems, initializing any systems with the same `hash`
+/// for the same schedule does nothing.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_init_marker::{dedup_by, Initialized};
+/// # use bevy::prelude::*;
+/// #
+/// # let mut world = World::new();
+/// #
+/// fn generate_system(value: u32) -> impl FnMut() {
+///     move || println!("{value}")
+/// }
+///
+/// assert!(Initialized::init_configs(&mut world, Update, dedup_by(1, generate_system(1))));
+/// assert!(!Initialized::init_configs(&mut world, Update, dedup_by(1, generate_system(1))));
+///
+/// assert!(Initialized::init_configs(&mut world, Update, dedup_by(2, generate_system(2))));
+/// ```
+pub fn dedup_by<S, Marker>(hash: u64, systems: S) -> DedupBy<S>
+where
+    S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+{
+    DedupBy { hash, systems }
+}
+
+/// Deinitializes every hash and disables their systems, returning the number of hashes that were removed.
+pub(crate) fn clear_hashes(world: &mut World) -> usize {
+    let Some(hashes) = world.remove_resource::<InitializedHashes>() else {
+        return 0;
+    };
+    for (hash, schedule) in &hashes.0 {
+        remove_systems(world, RegistrationKey::Hash(*hash), *schedule);
+    }
+    hashes.0.len()
+}
+
+impl<S, M> IntoInitConfigs<DedupMarker<M>> for DedupBy<S>
+where
+    S: IntoSystemConfigs<M> + Send + Sync + 'static,
+{
+    #[track_caller]
+    fn init_configs(self, world: &mut World, schedule: InternedScheduleLabel) -> bool {
+        let inserted = world
+            .get_resource_or_insert_with(InitializedHashes::default)
+            .0
+            .insert((self.hash, schedule));
+        if !inserted {
+            return false;
+        }
+        trace!("Initialized hash `{}` for `{:?}`", self.hash, schedule);
+        add_systems(
+            world,
+            RegistrationKey::Hash(self.hash),
+            schedule,
+            self.systems,
+        );
+        true
+    }
+}
+
+impl Initialized<()> {
+    /// Initialize the `systems` if the `key` hasn't been initialized for the `schedule` yet.
+    ///
+    /// Useful when the `schedule` is computed at runtime, the dedup doesn't depend on the type of the label or the `systems`.
+    /// Shares its keys with [`dedup_by`], this is the same as
+    /// [`Initialized::init_configs(world, schedule, dedup_by(key, systems))`](Initialized::init_configs).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// fn my_system() {
+    ///     // do stuff
+    /// }
+    ///
+    /// let schedule = if cfg!(debug_assertions) { Update.intern() } else { PostUpdate.intern() };
+    ///
+    /// assert!(Initialized::init_systems_dyn(&mut world, schedule, 7, my_system));
+    /// assert!(!Initialized::init_systems_dyn(&mut world, schedule, 7, || {}));
+    /// ```
+    #[track_caller]
+    pub fn init_systems_dyn<S, Marker>(
+        world: &mut World,
+        schedule: InternedScheduleLabel,
+        key: u64,
+        systems: S,
+    ) -> bool
+    where
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        dedup_by(key, systems).init_configs(world, schedule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{dedup_by, Initialized};
+    use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+
+    #[derive(Resource, Default)]
+    struct Counter(usize);
+
+    #[test]
+    fn test_dedup_by() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+
+        // different closures with the same logic
+        let a = |mut counter: ResMut<Counter>| counter.0 += 1;
+        let b = |mut counter: ResMut<Counter>| counter.0 += 1;
+
+        assert!(Initialized::init_configs(
+            &mut world,
+            Update,
+            dedup_by(1, a)
+        ));
+        assert!(!Initialized::init_configs(
+            &mut world,
+            Update,
+            dedup_by(1, b)
+        ));
+        assert!(Initialized::init_configs(
+            &mut world,
+            PostUpdate,
+            dedup_by(1, b)
+        ));
+
+        world.run_schedule(Update);
+        assert_eq!(world.resource::<Counter>().0, 1);
+
+        // the hashes are independent of the type markers
+        assert!(Initialized::init_systems(&mut world, Update, a));
+    }
+
+    #[test]
+    fn test_init_systems_dyn() {
+        #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Computed(u8);
+
+        fn sys1() {}
+        fn sys2() {}
+
+        let mut world = World::new();
+        let a = Computed(0).intern();
+        let b = Computed(1).intern();
+
+        assert!(Initialized::init_systems_dyn(&mut world, a, 1, sys1));
+        assert!(!Initialized::init_systems_dyn(&mut world, a, 1, sys2));
+        assert!(Initialized::init_systems_dyn(&mut world, a, 2, sys1));
+        assert!(Initialized::init_systems_dyn(&mut world, b, 1, sys1));
+
+        // shares the keys with `dedup_by`
+        assert!(!Initialized::init_configs(&mut world, b, dedup_by(1, sys2)));
+    }
+
+    #[test]
+    fn test_dedup_by_clear_all() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+
+        let add = |n| move |mut counter: ResMut<Counter>| counter.0 += n;
+
+        assert!(Initialized::init_configs(
+            &mut world,
+            Update,
+            dedup_by(1, add(1))
+        ));
+        assert!(Initialized::init_configs(
+            &mut world,
+            Update,
+            dedup_by(2, add(10))
+        ));
+        assert_eq!(Initialized::clear_all(&mut world), 2);
+
+        // the systems of every hash are disabled, not only the last one
+        world.run_schedule(Update);
+        assert_eq!(world.resource::<Counter>().0, 0);
+
+        assert!(Initialized::init_configs(
+            &mut world,
+            Update,
+            dedup_by(1, add(1))
+        ));
+        world.run_schedule(Update);
+        assert_eq!(world.resource::<Counter>().0, 1);
+    }
+}
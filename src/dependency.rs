@@ -0,0 +1,129 @@
+use bevy_ecs::world::World;
+
+use crate::Initialized;
+
+/// The result of [`Initialized::init_after`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepResult {
+    /// The marker was initialized and the setup was run.
+    Done,
+    /// The marker had already been initialized.
+    AlreadyDone,
+    /// The dependency hasn't been initialized yet, so neither was the marker.
+    DependencyUnmet,
+}
+
+impl DepResult {
+    /// Returns `true` if the marker is initialized, either by this call or before it.
+    pub fn is_initialized(&self) -> bool {
+        matches!(self, Self::Done | Self::AlreadyDone)
+    }
+}
+
+impl<M: Send + Sync + 'static> Initialized<M> {
+    /// Initializes the `Initialized<M>` resource and runs `f` if it hasn't been initialized yet and `Initialized<D>` has.
+    ///
+    /// If the dependency `D` hasn't been initialized yet nothing happens and [`DepResult::DependencyUnmet`] is returned,
+    /// so the call can be retried later, like every frame until the dependency is met.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::{DepResult, Initialized};
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// struct Textures;
+    /// struct Level;
+    ///
+    /// let load_level = |world: &mut World| {
+    ///     // requires the textures to be loaded
+    /// };
+    ///
+    /// assert_eq!(Initialized::<Level>::init_after::<Textures>(&mut world, load_level), DepResult::DependencyUnmet);
+    ///
+    /// let _ = Initialized::<Textures>::init(&mut world);
+    /// assert_eq!(Initialized::<Level>::init_after::<Textures>(&mut world, load_level), DepResult::Done);
+    /// assert_eq!(Initialized::<Level>::init_after::<Textures>(&mut world, load_level), DepResult::AlreadyDone);
+    /// ```
+    #[must_use]
+    #[track_caller]
+    pub fn init_after<D: Send + Sync + 'static>(
+        world: &mut World,
+        f: impl FnOnce(&mut World),
+    ) -> DepResult {
+        if Self::is_initialized(world) {
+            return DepResult::AlreadyDone;
+        }
+        if !Initialized::<D>::is_initialized(world) {
+            trace!(
+                "Skipped `{}` since `{}` is not initialized",
+                std::any::type_name::<M>(),
+                std::any::type_name::<D>()
+            );
+            return DepResult::DependencyUnmet;
+        }
+        if Self::init_with(world, f) {
+            DepResult::Done
+        } else {
+            DepResult::AlreadyDone
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DepResult, Initialized};
+    use bevy::prelude::*;
+
+    #[derive(Resource, Default)]
+    struct Order(Vec<&'static str>);
+
+    #[test]
+    fn test_init_after() {
+        struct A;
+        struct B;
+        struct C;
+
+        let mut world = World::new();
+        world.init_resource::<Order>();
+
+        let push = |name| move |world: &mut World| world.resource_mut::<Order>().0.push(name);
+
+        // C depends on B, which depends on A
+        assert_eq!(
+            Initialized::<C>::init_after::<B>(&mut world, push("c")),
+            DepResult::DependencyUnmet
+        );
+        assert_eq!(
+            Initialized::<B>::init_after::<A>(&mut world, push("b")),
+            DepResult::DependencyUnmet
+        );
+        assert!(!Initialized::<B>::is_initialized(&world));
+
+        assert!(Initialized::<A>::init_with(&mut world, push("a")));
+
+        // retry
+        assert_eq!(
+            Initialized::<C>::init_after::<B>(&mut world, push("c")),
+            DepResult::DependencyUnmet
+        );
+        assert_eq!(
+            Initialized::<B>::init_after::<A>(&mut world, push("b")),
+            DepResult::Done
+        );
+        assert_eq!(
+            Initialized::<C>::init_after::<B>(&mut world, push("c")),
+            DepResult::Done
+        );
+        assert_eq!(
+            Initialized::<C>::init_after::<B>(&mut world, push("c")),
+            DepResult::AlreadyDone
+        );
+
+        assert_eq!(world.resource::<Order>().0, ["a", "b", "c"]);
+        assert!(DepResult::AlreadyDone.is_initialized());
+        assert!(!DepResult::DependencyUnmet.is_initialized());
+    }
+}
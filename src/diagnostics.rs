@@ -0,0 +1,68 @@
+use bevy_app::{App, Plugin, Update};
+use bevy_diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+use bevy_ecs::system::Res;
+
+use crate::InitRegistry;
+
+/// Adds a diagnostic for the number of initialized markers in the [`InitRegistry`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_init_marker::InitMarkerDiagnosticsPlugin;
+/// # use bevy::{diagnostic::LogDiagnosticsPlugin, prelude::*};
+/// #
+/// # let mut app = App::new();
+/// #
+/// app.add_plugins((InitMarkerDiagnosticsPlugin, LogDiagnosticsPlugin::default()));
+/// ```
+#[derive(Default)]
+pub struct InitMarkerDiagnosticsPlugin;
+
+impl Plugin for InitMarkerDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::INITIALIZED))
+            .add_systems(Update, Self::diagnostic_system);
+    }
+}
+
+impl InitMarkerDiagnosticsPlugin {
+    /// The number of initialized markers.
+    pub const INITIALIZED: DiagnosticPath = DiagnosticPath::const_new("init_marker/initialized");
+
+    /// Records the number of markers in the [`InitRegistry`].
+    pub fn diagnostic_system(mut diagnostics: Diagnostics, registry: Option<Res<InitRegistry>>) {
+        diagnostics.add_measurement(&Self::INITIALIZED, || {
+            registry.map_or(0, |registry| registry.len()) as f64
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{InitMarkerDiagnosticsPlugin, Initialized};
+    use bevy::{diagnostic::DiagnosticsStore, prelude::*};
+
+    #[test]
+    fn test_diagnostics_plugin() {
+        struct A;
+        struct B;
+
+        let mut app = App::new();
+        app.add_plugins(InitMarkerDiagnosticsPlugin);
+
+        app.update();
+        let value = |app: &App| {
+            app.world
+                .resource::<DiagnosticsStore>()
+                .get(&InitMarkerDiagnosticsPlugin::INITIALIZED)
+                .and_then(|diagnostic| diagnostic.value())
+        };
+        assert_eq!(value(&app), Some(0.0));
+
+        assert!(Initialized::<A>::init(&mut app.world));
+        assert!(Initialized::<B>::init(&mut app.world));
+        app.update();
+        assert_eq!(value(&app), Some(2.0));
+    }
+}
@@ -0,0 +1,272 @@
+use std::{any::TypeId, fmt::Debug, hash::Hash};
+
+use bevy_ecs::{system::Resource, world::World};
+use bevy_utils::HashSet;
+
+use crate::Initialized;
+
+/// A [`Resource`] for runtime keys that have been initialized.
+///
+/// Like [`Initialized`](crate::Initialized) but keyed on a value of `K` rather than a type,
+/// useful when the marker can't be named as a type at compile time.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_init_marker::DynInitialized;
+/// # use bevy::prelude::*;
+/// # use std::any::TypeId;
+/// #
+/// # let mut world = World::new();
+/// #
+/// struct MyMarker;
+///
+/// if DynInitialized::init(&mut world, TypeId::of::<MyMarker>()) {
+///     // do stuff once
+/// }
+///
+/// if DynInitialized::init(&mut world, "my_subsystem") {
+///     // do stuff once
+/// }
+/// ```
+#[derive(Resource)]
+pub struct DynInitialized<K: Hash + Eq + Send + Sync + 'static = TypeId>(HashSet<K>);
+
+impl<K: Hash + Eq + Send + Sync + 'static> Debug for DynInitialized<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "DynInitialized<{}>({})",
+            std::any::type_name::<K>(),
+            self.0.len()
+        )
+    }
+}
+
+impl<K: Hash + Eq + Send + Sync + 'static> Default for DynInitialized<K> {
+    fn default() -> Self {
+        Self(HashSet::default())
+    }
+}
+
+impl<K: Hash + Eq + Send + Sync + 'static> DynInitialized<K> {
+    /// Initializes the `key` if it hasn't been initialized yet.
+    ///
+    /// Returns `true` if the `key` was not previously initialized, `false` otherwise.
+    #[must_use]
+    pub fn init(world: &mut World, key: K) -> bool {
+        let mut initialized = world.get_resource_or_insert_with(Self::default);
+        let inserted = initialized.0.insert(key);
+        if inserted {
+            trace!("Initialized `{}` key", std::any::type_name::<K>());
+        }
+        inserted
+    }
+
+    /// Returns `true` if the `key` has been initialized.
+    #[must_use]
+    pub fn is_initialized(world: &World, key: &K) -> bool {
+        world
+            .get_resource::<Self>()
+            .is_some_and(|initialized| initialized.0.contains(key))
+    }
+
+    /// Removes the `key` so that the next [`DynInitialized::init`] returns `true` again.
+    ///
+    /// Returns `true` if the `key` was previously initialized, `false` otherwise.
+    pub fn deinit(world: &mut World, key: &K) -> bool {
+        world
+            .get_resource_mut::<Self>()
+            .is_some_and(|mut initialized| initialized.0.remove(key))
+    }
+
+    /// Returns an iterator over the initialized keys.
+    pub fn iter(&self) -> impl Iterator<Item = &K> {
+        self.0.iter()
+    }
+}
+
+/// A value that provides its own initialization key, see [`Initialized::init_dyn`].
+///
+/// Lets the variants of one enum be initialized independently without a marker type for every variant.
+/// The keys are namespaced by the implementing type, so different types can use the same keys.
+pub trait InitKey: 'static {
+    /// Returns the initialization key of this value.
+    fn init_key(&self) -> u64;
+}
+
+impl Initialized<()> {
+    /// Initializes the runtime `key` if it hasn't been initialized yet.
+    ///
+    /// Returns `true` if the `key` was not previously initialized, `false` otherwise.
+    ///
+    /// Useful when the marker can't be a `'static` type, like for systems that are generic over lifetimes.
+    /// The keys are stored in [`DynInitialized<K>`], which can be used to check or remove them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::{DynInitialized, Initialized};
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// #[derive(Hash, PartialEq, Eq)]
+    /// enum Subsystem {
+    ///     Audio,
+    ///     Input,
+    /// }
+    ///
+    /// assert!(Initialized::init_keyed(&mut world, Subsystem::Audio));
+    /// assert!(!Initialized::init_keyed(&mut world, Subsystem::Audio));
+    ///
+    /// assert!(!DynInitialized::is_initialized(&world, &Subsystem::Input));
+    /// ```
+    #[must_use]
+    pub fn init_keyed<K: Hash + Eq + Send + Sync + 'static>(world: &mut World, key: K) -> bool {
+        DynInitialized::init(world, key)
+    }
+
+    /// Initializes the key provided by the `key_provider` if it hasn't been initialized yet.
+    ///
+    /// Returns `true` if the key was not previously initialized, `false` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::{InitKey, Initialized};
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// enum Subsystem {
+    ///     Audio,
+    ///     Input,
+    /// }
+    ///
+    /// impl InitKey for Subsystem {
+    ///     fn init_key(&self) -> u64 {
+    ///         match self {
+    ///             Subsystem::Audio => 0,
+    ///             Subsystem::Input => 1,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// assert!(Initialized::init_dyn(&mut world, Subsystem::Audio));
+    /// assert!(!Initialized::init_dyn(&mut world, Subsystem::Audio));
+    ///
+    /// assert!(Initialized::init_dyn(&mut world, Subsystem::Input));
+    /// ```
+    #[must_use]
+    pub fn init_dyn<K: InitKey>(world: &mut World, key_provider: K) -> bool {
+        DynInitialized::init(world, dyn_key(&key_provider))
+    }
+
+    /// Returns `true` if the key provided by the `key_provider` has been initialized by [`Initialized::init_dyn`].
+    #[must_use]
+    pub fn is_dyn_initialized<K: InitKey>(world: &World, key_provider: &K) -> bool {
+        DynInitialized::is_initialized(world, &dyn_key(key_provider))
+    }
+
+    /// Removes the key provided by the `key_provider` so that the next [`Initialized::init_dyn`] returns `true` again.
+    ///
+    /// Returns `true` if the key was previously initialized, `false` otherwise.
+    pub fn deinit_dyn<K: InitKey>(world: &mut World, key_provider: &K) -> bool {
+        DynInitialized::deinit(world, &dyn_key(key_provider))
+    }
+}
+
+/// Returns the key of the `key_provider` namespaced by its type.
+fn dyn_key<K: InitKey>(key_provider: &K) -> (TypeId, u64) {
+    (TypeId::of::<K>(), key_provider.init_key())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use crate::{DynInitialized, InitKey, Initialized};
+    use bevy::prelude::*;
+
+    #[test]
+    fn test_dyn_init() {
+        let mut world = World::new();
+
+        assert!(DynInitialized::init(&mut world, TypeId::of::<u8>()));
+        assert!(!DynInitialized::init(&mut world, TypeId::of::<u8>()));
+        assert!(DynInitialized::init(&mut world, TypeId::of::<u16>()));
+
+        assert!(DynInitialized::init(&mut world, "a"));
+        assert!(!DynInitialized::init(&mut world, "a"));
+        assert!(DynInitialized::is_initialized(&world, &"a"));
+        assert!(!DynInitialized::is_initialized(&world, &"b"));
+
+        assert!(DynInitialized::deinit(&mut world, &"a"));
+        assert!(!DynInitialized::deinit(&mut world, &"a"));
+        assert!(DynInitialized::init(&mut world, "a"));
+    }
+
+    #[test]
+    fn test_init_keyed() {
+        let mut world = World::new();
+
+        assert!(Initialized::init_keyed(&mut world, 1_u64));
+        assert!(!Initialized::init_keyed(&mut world, 1_u64));
+        assert!(Initialized::init_keyed(&mut world, 2_u64));
+        assert!(DynInitialized::is_initialized(&world, &1_u64));
+    }
+
+    #[test]
+    fn test_init_dyn() {
+        enum Subsystem {
+            Audio,
+            Input,
+            Network { port: u16 },
+        }
+
+        impl InitKey for Subsystem {
+            fn init_key(&self) -> u64 {
+                match self {
+                    Subsystem::Audio => 0,
+                    Subsystem::Input => 1,
+                    Subsystem::Network { port } => 2 + u64::from(*port),
+                }
+            }
+        }
+
+        struct Other;
+
+        impl InitKey for Other {
+            fn init_key(&self) -> u64 {
+                0
+            }
+        }
+
+        let mut world = World::new();
+
+        assert!(Initialized::init_dyn(&mut world, Subsystem::Audio));
+        assert!(!Initialized::init_dyn(&mut world, Subsystem::Audio));
+        assert!(Initialized::init_dyn(&mut world, Subsystem::Input));
+        assert!(Initialized::init_dyn(
+            &mut world,
+            Subsystem::Network { port: 80 }
+        ));
+        assert!(Initialized::init_dyn(
+            &mut world,
+            Subsystem::Network { port: 443 }
+        ));
+        assert!(!Initialized::init_dyn(
+            &mut world,
+            Subsystem::Network { port: 80 }
+        ));
+
+        // the same key of another type is independent
+        assert!(Initialized::init_dyn(&mut world, Other));
+
+        assert!(Initialized::deinit_dyn(&mut world, &Subsystem::Input));
+        assert!(!Initialized::is_dyn_initialized(&world, &Subsystem::Input));
+        assert!(Initialized::is_dyn_initialized(&world, &Subsystem::Audio));
+        assert!(Initialized::init_dyn(&mut world, Subsystem::Input));
+    }
+}
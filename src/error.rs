@@ -0,0 +1,28 @@
+use std::fmt::{self, Display};
+
+/// An error returned by the fallible functions on [`Initialized`](crate::Initialized).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InitError {
+    /// The [`Schedules`](bevy_ecs::schedule::Schedules) resource does not exist in the world.
+    MissingSchedules,
+    /// The schedule had systems added to it but is missing from [`Schedules`](bevy_ecs::schedule::Schedules).
+    ///
+    /// This usually means the schedule is currently running, and the schedule would be overwritten
+    /// together with any systems added to it once the run finishes.
+    ScheduleFinalized,
+}
+
+impl Display for InitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingSchedules => write!(f, "`Schedules` resource does not exist in the world"),
+            Self::ScheduleFinalized => write!(
+                f,
+                "schedule is missing from `Schedules`, it's probably running"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InitError {}
@@ -0,0 +1,92 @@
+use bevy_ecs::{
+    event::{Event, Events},
+    world::World,
+};
+
+/// An [`Event`] sent when an [`Initialized`](crate::Initialized) marker is first initialized.
+///
+/// Only sent if the [`Events<MarkerInitialized>`] resource exists in the world.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_init_marker::{Initialized, MarkerInitialized};
+/// # use bevy::prelude::*;
+/// #
+/// # let mut app = App::new();
+/// #
+/// struct MyMarker;
+///
+/// app.add_event::<MarkerInitialized>();
+///
+/// fn log_initialized(mut events: EventReader<MarkerInitialized>) {
+///     for event in events.read() {
+///         println!("initialized {}", event.type_name);
+///     }
+/// }
+///
+/// app.add_systems(Update, log_initialized);
+///
+/// let _ = Initialized::<MyMarker>::init(&mut app.world);
+/// ```
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MarkerInitialized {
+    /// The type name of the marker.
+    pub type_name: &'static str,
+}
+
+/// Sends a [`MarkerInitialized`] event for the marker `M` if the [`Events<MarkerInitialized>`] resource exists.
+pub(crate) fn send_initialized<M: 'static>(world: &mut World) {
+    if let Some(mut events) = world.get_resource_mut::<Events<MarkerInitialized>>() {
+        events.send(MarkerInitialized {
+            type_name: std::any::type_name::<M>(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{run_once, Initialized, MarkerInitialized};
+    use bevy::{ecs::event::Events, prelude::*};
+
+    #[test]
+    fn test_marker_initialized_event() {
+        struct MyMarker;
+
+        let mut world = World::new();
+        world.init_resource::<Events<MarkerInitialized>>();
+
+        assert!(Initialized::<MyMarker>::init(&mut world));
+        assert!(!Initialized::<MyMarker>::init(&mut world));
+
+        let events = world.resource::<Events<MarkerInitialized>>();
+        let sent: Vec<_> = events.get_reader().read(events).copied().collect();
+        assert_eq!(
+            sent,
+            [MarkerInitialized {
+                type_name: std::any::type_name::<MyMarker>()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_marker_initialized_after_run_once() {
+        struct MyMarker;
+
+        let mut app = App::new();
+        app.add_event::<MarkerInitialized>()
+            .add_systems(Update, (|| {}).run_if(run_once::<MyMarker>()));
+        app.update();
+
+        // the resource only catches up with the run, it's not a new initialization
+        assert!(!Initialized::<MyMarker>::init(&mut app.world));
+        let events = app.world.resource::<Events<MarkerInitialized>>();
+        assert_eq!(events.get_reader().read(events).count(), 0);
+    }
+
+    #[test]
+    fn test_marker_initialized_without_events() {
+        let mut world = World::new();
+        assert!(Initialized::<()>::init(&mut world));
+    }
+}
@@ -0,0 +1,119 @@
+use std::{
+    any::TypeId,
+    sync::{Mutex, OnceLock},
+};
+
+use bevy_ecs::{
+    schedule::{IntoSystemConfigs, ScheduleLabel},
+    world::World,
+};
+use bevy_utils::HashSet;
+
+use crate::{add_systems, Initialized};
+
+/// The `(L, S)` pairs initialized by [`Initialized::init_systems_global`], shared by every world in the process.
+static GLOBAL: OnceLock<Mutex<HashSet<(TypeId, TypeId)>>> = OnceLock::new();
+
+impl Initialized<()> {
+    /// Initialize the `systems` if they hasn't been initialized for the `schedule` in *any* world yet.
+    ///
+    /// Unlike [`Initialized::init_systems`], which stores its marker in the `world`,
+    /// the marker is stored in a process-wide set, so the `systems` are only added to the first world that calls this.
+    /// This is useful when the same setup runs for several worlds, like the main world and the render world,
+    /// but must only happen once.
+    ///
+    /// The markers can't be removed and are shared between every [`App`](bevy_app::App) in the process,
+    /// including those created by other tests running in the same process.
+    ///
+    /// Returns `true` if the systems were added, `false` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// let mut main_world = World::new();
+    /// let mut render_world = World::new();
+    ///
+    /// fn my_system() {
+    ///     // do stuff
+    /// }
+    ///
+    /// assert!(Initialized::init_systems_global(&mut main_world, Update, my_system));
+    /// assert!(!Initialized::init_systems_global(&mut render_world, Update, my_system));
+    /// ```
+    #[track_caller]
+    pub fn init_systems_global<L, S, Marker>(world: &mut World, schedule: L, systems: S) -> bool
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        let key = (TypeId::of::<L>(), TypeId::of::<S>());
+        let inserted = GLOBAL
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .insert(key);
+        if inserted {
+            trace!(
+                "Initialized `{}` globally for `{:?}`",
+                std::any::type_name::<S>(),
+                schedule
+            );
+            add_systems(world, TypeId::of::<(L, S)>(), schedule.intern(), systems);
+        }
+        inserted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Initialized;
+    use bevy::prelude::*;
+
+    #[test]
+    fn test_init_systems_global() {
+        fn sys1() {}
+
+        let mut world_a = World::new();
+        let mut world_b = World::new();
+
+        assert!(Initialized::init_systems_global(&mut world_a, Update, sys1));
+        assert!(!Initialized::init_systems_global(
+            &mut world_a,
+            Update,
+            sys1
+        ));
+        assert!(!Initialized::init_systems_global(
+            &mut world_b,
+            Update,
+            sys1
+        ));
+        assert!(!world_b.contains_resource::<Schedules>());
+
+        // the per-world marker is independent
+        assert!(Initialized::init_systems(&mut world_b, Update, sys1));
+    }
+
+    #[test]
+    fn test_init_systems_global_threads() {
+        fn sys1() {}
+
+        let added: usize = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..8)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut world = World::new();
+                        Initialized::init_systems_global(&mut world, Update, sys1)
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap() as usize)
+                .sum()
+        });
+        assert_eq!(added, 1);
+    }
+}
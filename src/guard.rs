@@ -0,0 +1,161 @@
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+use bevy_ecs::world::World;
+
+use crate::Initialized;
+
+/// A guard for a fallible one-time setup, returned by [`Initialized::guard`].
+///
+/// If this was the first initialization the marker is deinitialized again when the guard is dropped,
+/// unless [`InitGuard::commit`] was called, so a setup that fails or panics can be retried.
+///
+/// The guard dereferences to the [`World`] so the setup can use it.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_init_marker::Initialized;
+/// # use bevy::prelude::*;
+/// #
+/// # let mut world = World::new();
+/// #
+/// struct MyMarker;
+///
+/// fn setup(world: &mut World) -> Result<(), String> {
+///     Err("not ready yet".into())
+/// }
+///
+/// {
+///     let mut guard = Initialized::<MyMarker>::guard(&mut world);
+///     if guard.is_first() && setup(&mut guard).is_ok() {
+///         guard.commit();
+///     }
+/// }
+///
+/// // the setup failed so the marker was rolled back
+/// assert!(!Initialized::<MyMarker>::is_initialized(&world));
+/// ```
+#[must_use]
+pub struct InitGuard<'w, M: Send + Sync + 'static> {
+    world: &'w mut World,
+    first: bool,
+    committed: bool,
+    _marker: PhantomData<fn() -> M>,
+}
+
+impl<M: Send + Sync + 'static> InitGuard<'_, M> {
+    /// Returns `true` if the marker was initialized by this guard.
+    pub fn is_first(&self) -> bool {
+        self.first
+    }
+
+    /// Keeps the marker initialized when the guard is dropped.
+    pub fn commit(mut self) {
+        self.committed = true;
+    }
+}
+
+impl<M: Send + Sync + 'static> Deref for InitGuard<'_, M> {
+    type Target = World;
+
+    fn deref(&self) -> &Self::Target {
+        self.world
+    }
+}
+
+impl<M: Send + Sync + 'static> DerefMut for InitGuard<'_, M> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.world
+    }
+}
+
+impl<M: Send + Sync + 'static> Drop for InitGuard<'_, M> {
+    fn drop(&mut self) {
+        if self.first && !self.committed {
+            trace!(
+                "Rolled back `{}` since it was not committed",
+                std::any::type_name::<M>()
+            );
+            let _ = Initialized::<M>::deinit(self.world);
+        }
+    }
+}
+
+impl<M: Send + Sync + 'static> Debug for InitGuard<'_, M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InitGuard")
+            .field("marker", &std::any::type_name::<M>())
+            .field("first", &self.first)
+            .field("committed", &self.committed)
+            .finish()
+    }
+}
+
+impl<M: Send + Sync + 'static> Initialized<M> {
+    /// Initializes the `Initialized<M>` resource and returns an [`InitGuard`] that rolls it back unless committed.
+    ///
+    /// See [`InitGuard`].
+    #[track_caller]
+    pub fn guard(world: &mut World) -> InitGuard<'_, M> {
+        let first = Self::init(world);
+        InitGuard {
+            world,
+            first,
+            committed: false,
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    use crate::Initialized;
+    use bevy::prelude::*;
+
+    #[test]
+    fn test_guard_commit() {
+        struct MyMarker;
+
+        let mut world = World::new();
+        let guard = Initialized::<MyMarker>::guard(&mut world);
+        assert!(guard.is_first());
+        guard.commit();
+        assert!(Initialized::<MyMarker>::is_initialized(&world));
+
+        // not the first, dropping doesn't deinitialize
+        let guard = Initialized::<MyMarker>::guard(&mut world);
+        assert!(!guard.is_first());
+        drop(guard);
+        assert!(Initialized::<MyMarker>::is_initialized(&world));
+    }
+
+    #[test]
+    fn test_guard_rollback() {
+        struct MyMarker;
+
+        #[derive(Resource)]
+        struct HalfInitialized;
+
+        let mut world = World::new();
+        drop(Initialized::<MyMarker>::guard(&mut world));
+        assert!(!Initialized::<MyMarker>::is_initialized(&world));
+
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            let mut guard = Initialized::<MyMarker>::guard(&mut world);
+            assert!(guard.is_first());
+            guard.insert_resource(HalfInitialized);
+            panic!("setup failed");
+        }));
+        assert!(result.is_err());
+        assert!(!Initialized::<MyMarker>::is_initialized(&world));
+
+        // can be retried
+        assert!(Initialized::<MyMarker>::guard(&mut world).is_first());
+    }
+}
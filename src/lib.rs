@@ -1,16 +1,117 @@
-use std::{fmt::Debug, marker::PhantomData};
+use std::{
+    any::{Any, TypeId},
+    fmt::Debug,
+    hash::Hash,
+    marker::PhantomData,
+    panic::Location,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 
+#[cfg(feature = "reflect")]
+use bevy_ecs::reflect::ReflectResource;
 use bevy_ecs::{
-    schedule::{IntoSystemConfigs, Schedule, ScheduleLabel, Schedules},
-    system::Resource,
+    schedule::{
+        Condition, InternedScheduleLabel, IntoSystemConfigs, IntoSystemSet, IntoSystemSetConfigs,
+        NodeId, Schedule, ScheduleBuildSettings, ScheduleLabel, Schedules, SystemConfigs,
+    },
+    system::{IntoSystem, Resource, RunSystemOnce, System},
     world::World,
 };
-use bevy_reflect::Reflect;
+#[cfg(feature = "reflect")]
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_utils::{HashMap, HashSet};
+
+#[macro_use]
+mod macros;
+
+mod aliases;
+mod app;
+#[cfg(feature = "asset")]
+mod asset;
+mod builder;
+mod commands;
+mod component;
+mod condition;
+mod configs;
+#[cfg(feature = "debug_checks")]
+mod debug_checks;
+mod dedup;
+mod dependency;
+#[cfg(feature = "diagnostics")]
+mod diagnostics;
+mod dynamic;
+mod error;
+#[cfg(feature = "events")]
+mod events;
+mod global;
+mod guard;
+mod outcome;
+mod param;
+mod pending;
+mod plugin;
+mod refcount;
+mod registry;
+mod scoped;
+mod set;
+mod snapshot;
+mod states;
+#[cfg(feature = "stats")]
+mod stats;
+mod strict;
+mod target;
+mod this_run;
+mod token;
+mod with;
+mod world;
+
+pub use app::InitMarkerAppExt;
+pub use builder::InitBuilder;
+pub use commands::InitMarkerCommandsExt;
+pub use component::{
+    InitMarkerEntityCommandsExt, InitMarkerEntityWorldMutExt, InitializedComponent,
+};
+pub use condition::{run_once, InitMarkerSystemsExt};
+pub use configs::IntoInitConfigs;
+#[cfg(feature = "debug_checks")]
+pub use debug_checks::DuplicateSystemCheck;
+pub use dedup::{dedup_by, DedupBy};
+pub use dependency::DepResult;
+#[cfg(feature = "diagnostics")]
+pub use diagnostics::InitMarkerDiagnosticsPlugin;
+pub use dynamic::{DynInitialized, InitKey};
+pub use error::InitError;
+#[cfg(feature = "events")]
+pub use events::MarkerInitialized;
+pub use guard::InitGuard;
+pub use outcome::{InitOutcome, ScheduleStatus};
+pub use param::InitOnce;
+pub use pending::{apply_pending_inits, apply_pending_inits_budgeted, PendingInits};
+pub use plugin::InitMarkerPlugin;
+pub use registry::{InitEntry, InitRegistry};
+pub use scoped::clear_orphaned_markers;
+pub use set::InitializedSet;
+pub use snapshot::InitSnapshot;
+#[cfg(feature = "stats")]
+pub use stats::{InitStats, MarkerStats};
+pub use target::InitTarget;
+pub use this_run::{reset_per_run_markers, InitializedThisRun};
+pub use token::InitToken;
+pub use with::InitializedWith;
+pub use world::WorldInitExt;
 
 /// A Marker [`Resource`] for *something* that has been initialized.
 ///
 /// Usefull if you need to add a system after the app has started but want to ensure that it only happens once (since there is no way to know if the system has already been added otherwise).
 ///
+/// Markers are stored per [`World`], so every [`SubApp`](bevy_app::SubApp) has its own markers,
+/// and initializing a marker in a sub app doesn't initialize it in the main world or other sub apps.
+/// To initialize something once per [`App`](bevy_app::App), always initialize the marker in the main world (`app.world`),
+/// a sub app can't reach the main world of its parent.
+/// [`Initialized::init_systems_global`] dedups across every world in the process instead.
+///
 /// # Examples
 ///
 /// ```
@@ -34,8 +135,11 @@ use bevy_reflect::Reflect;
 ///     println!("initialized my_system!");
 /// }
 /// ```
-#[derive(Resource, Reflect)]
-pub struct Initialized<M: Send + Sync + 'static>(PhantomData<M>);
+#[derive(Resource)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Resource, Default))]
+pub struct Initialized<M: Send + Sync + 'static>(
+    #[cfg_attr(feature = "reflect", reflect(ignore))] PhantomData<M>,
+);
 
 impl<M: Send + Sync + 'static> Debug for Initialized<M> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -57,11 +161,49 @@ impl<M: Send + Sync + 'static> Default for Initialized<M> {
     }
 }
 
+/// Asserts that the marker `M` is zero sized, meant to be evaluated in a `const` so the check happens at compile time.
+///
+/// `Initialized<M>` never stores a value of `M`, so a marker with data is usually a mistake,
+/// use [`InitializedWith<M>`] to store the value along with the marker.
+///
+/// # Panics
+///
+/// Panics if `M` is not zero sized, which fails to compile in a `const`.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_init_marker::assert_zst_marker;
+/// #
+/// struct MyMarker;
+///
+/// const _: () = assert_zst_marker::<MyMarker>();
+/// ```
+///
+/// ```compile_fail
+/// # use bevy_init_marker::assert_zst_marker;
+/// #
+/// struct MySettings {
+///     volume: f32,
+/// }
+///
+/// // use `InitializedWith<MySettings>` instead
+/// const _: () = assert_zst_marker::<MySettings>();
+/// ```
+pub const fn assert_zst_marker<M>() {
+    assert!(
+        std::mem::size_of::<M>() == 0,
+        "the marker is not zero sized, use `InitializedWith` to store data"
+    );
+}
+
 impl<M: Send + Sync + 'static> Initialized<M> {
     /// Initializes the `Initialized<M>` resource if it hasn't been initialized yet.
     ///
     /// Returns `true` if the resource was not previously initialized, `false` otherwise.
     ///
+    /// The `world` can be anything implementing [`InitTarget`], usually a [`World`].
+    ///
     /// See also [`Initialized::init_system`].
     ///
     /// # Example
@@ -79,25 +221,373 @@ impl<M: Send + Sync + 'static> Initialized<M> {
     /// }
     /// ```
     #[must_use]
-    pub fn init(world: &mut World) -> bool {
+    #[track_caller]
+    pub fn init(world: &mut impl InitTarget) -> bool {
+        let initialized = world.insert::<M>();
+        if initialized {
+            trace!("Initialized `{}`", std::any::type_name::<M>());
+        }
+        initialized
+    }
+
+    /// Same as [`Initialized::init`] but logs the initialization at the given `level` instead of `TRACE`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::{log::Level, prelude::*};
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// struct MySubsystem;
+    ///
+    /// if Initialized::<MySubsystem>::init_log(&mut world, Level::INFO) {
+    ///     // do stuff once
+    /// }
+    /// ```
+    #[cfg(feature = "log")]
+    #[must_use]
+    #[track_caller]
+    pub fn init_log(world: &mut World, level: bevy_log::Level) -> bool {
+        let initialized = Self::init_inner(world, true);
+        if initialized {
+            Self::log_initialized(level);
+        }
+        initialized
+    }
+
+    /// Logs that the marker was initialized at the given `level`.
+    #[cfg(feature = "log")]
+    fn log_initialized(level: bevy_log::Level) {
+        let name = std::any::type_name::<M>();
+        match level {
+            bevy_log::Level::ERROR => bevy_log::error!("Initialized `{name}`"),
+            bevy_log::Level::WARN => bevy_log::warn!("Initialized `{name}`"),
+            bevy_log::Level::INFO => bevy_log::info!("Initialized `{name}`"),
+            bevy_log::Level::DEBUG => bevy_log::debug!("Initialized `{name}`"),
+            bevy_log::Level::TRACE => bevy_log::trace!("Initialized `{name}`"),
+        }
+    }
+
+    /// Same as [`Initialized::init`] but without logging, only sends the event if `emit_event` is `true`.
+    #[track_caller]
+    fn init_inner(world: &mut World, emit_event: bool) -> bool {
+        if !world.contains_resource::<Self>() && condition::take_claim::<M>(world) {
+            // a `run_once` condition already used up the marker, the resource is inserted to catch up
+            // without sending the event or counting it as an initialization
+            Self::insert_resource(world);
+            aliases::init_aliases(world, TypeId::of::<M>());
+            #[cfg(feature = "stats")]
+            InitStats::record::<M>(world, false);
+            return false;
+        }
+        let initialized = Self::insert(world, emit_event);
+        if initialized {
+            aliases::init_aliases(world, TypeId::of::<M>());
+        } else if Initialized::is_strict(world) {
+            panic!(
+                "`{}` has already been initialized with strict initialization enabled",
+                std::any::type_name::<M>()
+            );
+        }
+        initialized
+    }
+
+    /// Same as [`Initialized::init_inner`] but ignores the aliases of the marker.
+    #[track_caller]
+    fn insert(world: &mut World, emit_event: bool) -> bool {
+        #[cfg(not(feature = "events"))]
+        let _ = emit_event;
         if !world.contains_resource::<Self>() {
-            bevy_log::trace!("Initialized `{}`", std::any::type_name::<M>());
-            world.init_resource::<Self>();
+            Self::insert_resource(world);
+            #[cfg(feature = "events")]
+            if emit_event {
+                events::send_initialized::<M>(world);
+            }
+            #[cfg(feature = "stats")]
+            InitStats::record::<M>(world, true);
+            true
+        } else {
+            #[cfg(feature = "stats")]
+            InitStats::record::<M>(world, false);
+            false
+        }
+    }
+
+    /// Inserts the `Initialized<M>` resource and records it in the [`InitRegistry`].
+    #[track_caller]
+    fn insert_resource(world: &mut World) {
+        world.init_resource::<Self>();
+        InitRegistry::record::<M>(world, Location::caller());
+    }
+
+    /// Initializes the `Initialized<M>` resource and runs `f` if it hasn't been initialized yet.
+    ///
+    /// Returns `true` if `f` was run, `false` otherwise.
+    ///
+    /// The marker is initialized *before* `f` runs, so `f` is re-entrant:
+    /// initializing the same marker again from within `f` returns `false` instead of recursing.
+    /// With [`Initialized::set_strict`] enabled that nested initialization panics instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// struct MyMarker;
+    ///
+    /// Initialized::<MyMarker>::init_with(&mut world, |world| {
+    ///     // do stuff once
+    ///
+    ///     // the marker is already initialized here
+    ///     assert!(Initialized::<MyMarker>::is_initialized(world));
+    /// });
+    /// ```
+    #[track_caller]
+    pub fn init_with(world: &mut World, f: impl FnOnce(&mut World)) -> bool {
+        if Self::init(world) {
+            f(world);
+            return true;
+        }
+        false
+    }
+
+    /// Initializes the `Initialized<M>` resource if it hasn't been initialized yet and the `predicate` returns `true`.
+    ///
+    /// Returns `true` if the resource was initialized by this call, `false` otherwise.
+    ///
+    /// The `predicate` is evaluated on every call until it returns `true`, and never once the marker is initialized.
+    /// If it returns `false` the marker is not initialized so a later call can try again,
+    /// which is useful for waiting on a precondition like an asset being loaded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// #[derive(Resource)]
+    /// struct Config;
+    ///
+    /// struct MyMarker;
+    ///
+    /// let ready = |world: &World| world.contains_resource::<Config>();
+    ///
+    /// // waits for `Config`
+    /// assert!(!Initialized::<MyMarker>::init_if(&mut world, ready));
+    ///
+    /// world.insert_resource(Config);
+    /// assert!(Initialized::<MyMarker>::init_if(&mut world, ready));
+    /// assert!(!Initialized::<MyMarker>::init_if(&mut world, ready));
+    /// ```
+    #[must_use]
+    #[track_caller]
+    pub fn init_if(world: &mut World, predicate: impl FnOnce(&World) -> bool) -> bool {
+        if Self::is_initialized(world) || !predicate(world) {
+            return false;
+        }
+        Self::init(world)
+    }
+
+    /// Initializes the `Initialized<M>` resource and runs the `system` immediately if it hasn't been initialized yet.
+    ///
+    /// Returns `true` if the `system` was run, `false` otherwise.
+    ///
+    /// Unlike [`Initialized::init_systems`] the `system` is not added to a schedule,
+    /// this is the same as [`Initialized::init_with`] but the setup can use [`SystemParam`](bevy_ecs::system::SystemParam)s.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// struct MyMarker;
+    ///
+    /// fn setup(mut commands: Commands) {
+    ///     // do stuff once
+    /// }
+    ///
+    /// assert!(Initialized::<MyMarker>::init_run_system(&mut world, setup));
+    /// assert!(!Initialized::<MyMarker>::init_run_system(&mut world, setup));
+    /// ```
+    #[track_caller]
+    pub fn init_run_system<S, Marker>(world: &mut World, system: S) -> bool
+    where
+        S: IntoSystem<(), (), Marker>,
+    {
+        if Self::init(world) {
+            world.run_system_once(system);
+            return true;
+        }
+        false
+    }
+
+    /// Returns `true` if the `Initialized<M>` resource exists in the `world`.
+    ///
+    /// Unlike [`Initialized::init`] this never inserts the resource.
+    /// It's a single resource lookup that never allocates or logs, so it's cheap enough to call every frame.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// struct MyMarker;
+    ///
+    /// // checking does not initialize the marker
+    /// assert!(!Initialized::<MyMarker>::is_initialized(&world));
+    /// assert!(!Initialized::<MyMarker>::is_initialized(&world));
+    ///
+    /// // `init` does
+    /// assert!(Initialized::<MyMarker>::init(&mut world));
+    /// assert!(Initialized::<MyMarker>::is_initialized(&world));
+    /// ```
+    #[must_use]
+    pub fn is_initialized(world: &World) -> bool {
+        world.contains_resource::<Self>()
+    }
+
+    /// Removes the `Initialized<M>` resource so that the next [`Initialized::init`] returns `true` again.
+    ///
+    /// Returns `true` if the resource was previously initialized, `false` otherwise.
+    ///
+    /// The `world` can be anything implementing [`InitTarget`], usually a [`World`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// struct MyMarker;
+    ///
+    /// assert!(!Initialized::<MyMarker>::deinit(&mut world));
+    ///
+    /// assert!(Initialized::<MyMarker>::init(&mut world));
+    /// assert!(Initialized::<MyMarker>::deinit(&mut world));
+    ///
+    /// // can be initialized again
+    /// assert!(Initialized::<MyMarker>::init(&mut world));
+    /// ```
+    pub fn deinit(world: &mut impl InitTarget) -> bool {
+        world.remove::<M>()
+    }
+
+    /// Same as [`Initialized::deinit`] for a [`World`].
+    fn deinit_inner(world: &mut World) -> bool {
+        let deinitialized = Self::remove(world);
+        if deinitialized {
+            aliases::deinit_aliases(world, TypeId::of::<M>());
+        }
+        deinitialized
+    }
+
+    /// Same as [`Initialized::deinit`] but ignores the aliases of the marker.
+    fn remove(world: &mut World) -> bool {
+        if world.remove_resource::<Self>().is_some() {
+            InitRegistry::forget::<M>(world);
+            trace!("Deinitialized `{}`", std::any::type_name::<M>());
             true
         } else {
             false
         }
     }
+
+    /// Removes the `Initialized<M>` resource and runs `f` if it was initialized.
+    ///
+    /// Returns `true` if `f` was run, `false` otherwise.
+    ///
+    /// This is the counterpart to [`Initialized::init_with`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// struct MyMarker;
+    ///
+    /// Initialized::<MyMarker>::init_with(&mut world, |world| {
+    ///     // do stuff once
+    /// });
+    ///
+    /// Initialized::<MyMarker>::deinit_with(&mut world, |world| {
+    ///     // undo stuff once
+    /// });
+    /// ```
+    pub fn deinit_with(world: &mut World, f: impl FnOnce(&mut World)) -> bool {
+        if Self::deinit(world) {
+            f(world);
+            return true;
+        }
+        false
+    }
+
+    /// Initialize the `systems` if they hasn't been initialized for the `schedule` yet, scoped to the marker `M`.
+    ///
+    /// Same as [`Initialized::init_systems`] except that the marker also includes `M`,
+    /// so the same `systems` can be initialized once for every marker.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// # world.init_resource::<Schedules>();
+    /// #
+    /// struct MarkerA;
+    /// struct MarkerB;
+    ///
+    /// fn my_system() {
+    ///     // do stuff
+    /// }
+    ///
+    /// assert!(Initialized::<MarkerA>::init_systems_for(&mut world, Update, my_system));
+    /// assert!(Initialized::<MarkerB>::init_systems_for(&mut world, Update, my_system));
+    ///
+    /// assert!(!Initialized::<MarkerA>::init_systems_for(&mut world, Update, my_system));
+    /// ```
+    #[track_caller]
+    pub fn init_systems_for<L, S, Marker>(world: &mut World, schedule: L, systems: S) -> bool
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        init_systems_keyed::<(M, S), _>(world, schedule.intern(), systems, true, None)
+    }
 }
 
 impl Initialized<()> {
     /// Initialize the `systems` if they hasn't been initialized for the `schedule` yet.
     ///
-    /// See also [`Initialized::init`].
+    /// The [`Schedules`] resource is inserted if it does not exist in the `world` yet,
+    /// this is the recommended method for plugin authors since it doesn't depend on when it's called during setup.
     ///
-    /// # Panics
+    /// The marker is keyed on the type of the `systems` and the value of the `schedule` label,
+    /// so labels of the same type with different values, like `OnEnter(state)`, are initialized independently.
     ///
-    /// Panics if the [`Schedules`] resource does not exist int the `world`.
+    /// If the `schedule` doesn't exist yet and isn't one of the schedules run by bevy's [`Main`](bevy_app::Main) schedule,
+    /// a warning is logged since the created schedule may never run, use [`Initialized::init_systems_unchecked`] to skip the check.
+    ///
+    /// See also [`Initialized::init`], [`Initialized::init_systems_for`] and [`Initialized::init_systems_strict`].
     ///
     /// # Example
     ///
@@ -132,81 +622,2390 @@ impl Initialized<()> {
     /// #
     /// // `my_system` will be initialized twice here
     /// app.add_systems(Update, my_system);
-    /// Initialized::init_systems(&mut app.world, Update, my_system);
+    /// Initialized::ensure_systems(&mut app.world, Update, my_system);
     ///
     /// // `sys1` will be initialized twice here
-    /// Initialized::init_systems(&mut app.world, Update, sys1);
-    /// Initialized::init_systems(&mut app.world, Update, (sys1, sys2));
+    /// Initialized::ensure_systems(&mut app.world, Update, sys1);
+    /// Initialized::ensure_systems(&mut app.world, Update, (sys1, sys2));
     ///
     /// // these are two different systems and both will be initialized
-    /// Initialized::init_systems(&mut app.world, Update, || {});
-    /// Initialized::init_systems(&mut app.world, Update, || {});
+    /// Initialized::ensure_systems(&mut app.world, Update, || {});
+    /// Initialized::ensure_systems(&mut app.world, Update, || {});
     /// ```
+    #[must_use]
     #[track_caller]
     pub fn init_systems<L, S, Marker>(world: &mut World, schedule: L, systems: S) -> bool
     where
         L: ScheduleLabel,
         S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
     {
-        if Initialized::<(L, S)>::init(world) {
-            let mut schedules = world.resource_mut::<Schedules>();
-            match schedules.get_mut(schedule.intern()) {
-                Some(schedule) => {
-                    schedule.add_systems(systems);
-                }
-                None => {
-                    let mut schedule = Schedule::new(schedule);
-                    schedule.add_systems(systems);
-                    schedules.insert(schedule);
-                }
-            }
-            return true;
-        }
-        false
+        Initialized::<()>::init_systems_for(world, schedule, systems)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::Initialized;
-    use bevy::prelude::*;
+    /// Same as [`Initialized::init_systems`] but also runs the `schedule` once if the systems were added.
+    ///
+    /// Returns `true` if the systems were added and the schedule was run, `false` otherwise.
+    ///
+    /// Useful for bootstrapping a custom schedule that should run right away when its systems are registered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+    /// struct Bootstrap;
+    ///
+    /// fn setup() {
+    ///     // do stuff
+    /// }
+    ///
+    /// assert!(Initialized::init_systems_and_run(&mut world, Bootstrap, setup));
+    /// // not run again
+    /// assert!(!Initialized::init_systems_and_run(&mut world, Bootstrap, setup));
+    /// ```
+    #[must_use]
+    #[track_caller]
+    pub fn init_systems_and_run<L, S, Marker>(world: &mut World, schedule: L, systems: S) -> bool
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        let label = schedule.intern();
+        if !init_systems_keyed::<((), S), _>(world, label, systems, false, None) {
+            return false;
+        }
+        world.run_schedule(label);
+        true
+    }
 
-    #[test]
-    fn test_init() {
-        let mut world = World::new();
-        assert!(Initialized::<()>::init(&mut world));
+    /// Same as [`Initialized::init_systems`] but for when only the systems being added matters, not whether they were added by this call.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// fn my_system() {
+    ///     // do stuff
+    /// }
+    ///
+    /// Initialized::ensure_systems(&mut world, Update, my_system);
+    /// Initialized::ensure_systems(&mut world, Update, my_system);
+    ///
+    /// assert!(Initialized::systems_initialized(&world, Update, my_system));
+    /// ```
+    #[track_caller]
+    pub fn ensure_systems<L, S, Marker>(world: &mut World, schedule: L, systems: S)
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        let _ = Initialized::init_systems(world, schedule, systems);
+    }
+
+    /// Same as [`Initialized::init_systems`] but panics instead of inserting the [`Schedules`] resource if it's missing,
+    /// or instead of adding the systems to a schedule that is running.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`Schedules`] resource does not exist in the `world`, see [`InitError::MissingSchedules`].
+    ///
+    /// Panics if systems were added to the `schedule` before but it's missing from [`Schedules`], see [`InitError::ScheduleFinalized`].
+    ///
+    /// # Example
+    ///
+    /// ```should_panic
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// let mut world = World::new();
+    ///
+    /// fn my_system() {
+    ///     // do stuff
+    /// }
+    ///
+    /// // panics since `world` has no `Schedules`
+    /// Initialized::init_systems_strict(&mut world, Update, my_system);
+    /// ```
+    #[track_caller]
+    pub fn init_systems_strict<L, S, Marker>(world: &mut World, schedule: L, systems: S) -> bool
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        match Initialized::try_init_systems(world, schedule, systems) {
+            Ok(added) => added,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Same as [`Initialized::init_systems_strict`] but returns an error instead of panicking if the [`Schedules`] resource is missing
+    /// or the `schedule` is running.
+    ///
+    /// Returns `Ok(true)` if the systems were added, `Ok(false)` otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InitError::MissingSchedules`] if the [`Schedules`] resource does not exist in the `world`.
+    ///
+    /// Returns [`InitError::ScheduleFinalized`] if systems were added to the `schedule` before but it's missing from
+    /// [`Schedules`], which happens while the `schedule` is running. A running schedule is taken out of [`Schedules`]
+    /// and put back once it's done, which overwrites any schedule that was created in the meantime.
+    /// Bevy doesn't expose which schedules are running, so a schedule that never had systems added by this crate
+    /// can't be detected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::{InitError, Initialized};
+    /// # use bevy::prelude::*;
+    /// #
+    /// let mut world = World::new();
+    ///
+    /// fn my_system() {
+    ///     // do stuff
+    /// }
+    ///
+    /// assert_eq!(
+    ///     Initialized::try_init_systems(&mut world, Update, my_system),
+    ///     Err(InitError::MissingSchedules)
+    /// );
+    ///
+    /// world.init_resource::<Schedules>();
+    /// assert_eq!(Initialized::try_init_systems(&mut world, Update, my_system), Ok(true));
+    /// ```
+    pub fn try_init_systems<L, S, Marker>(
+        world: &mut World,
+        schedule: L,
+        systems: S,
+    ) -> Result<bool, InitError>
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        let Some(schedules) = world.get_resource::<Schedules>() else {
+            return Err(InitError::MissingSchedules);
+        };
+        let label = schedule.intern();
+        if !schedules.contains(label)
+            && world
+                .get_resource::<Registrations>()
+                .is_some_and(|registrations| registrations.0.keys().any(|(_, l)| *l == label))
+        {
+            return Err(InitError::ScheduleFinalized);
+        }
+        Ok(Initialized::init_systems(world, schedule, systems))
+    }
+
+    /// Initialize the type erased `systems` if the `key` hasn't been initialized for the `schedule` yet.
+    ///
+    /// Both the label and the systems are type erased, so registrations for different schedules and systems
+    /// can be stored together, like in a `Vec` built from a config.
+    /// The `key` identifies the systems instead of their type, it doesn't share a marker with [`Initialized::init_systems`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::any::TypeId;
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::{ecs::schedule::{InternedScheduleLabel, ScheduleLabel, SystemConfigs}, prelude::*};
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// fn physics() {}
+    /// fn render() {}
+    ///
+    /// let registrations: Vec<(InternedScheduleLabel, fn() -> SystemConfigs, TypeId)> = vec![
+    ///     (FixedUpdate.intern(), || physics.into_configs(), TypeId::of::<fn()>()),
+    ///     (PostUpdate.intern(), || render.into_configs(), TypeId::of::<fn()>()),
+    /// ];
+    ///
+    /// for (label, systems, key) in &registrations {
+    ///     assert!(Initialized::init_systems_erased(&mut world, *label, systems(), *key));
+    /// }
+    /// ```
+    #[must_use]
+    #[track_caller]
+    pub fn init_systems_erased(
+        world: &mut World,
+        schedule: InternedScheduleLabel,
+        systems: SystemConfigs,
+        key: TypeId,
+    ) -> bool {
+        let mut labels = world.get_resource_or_insert_with(InitializedLabels::default);
+        if labels.keys.contains(&(key, schedule)) {
+            if labels.strict {
+                panic!(
+                    "`{:?}` has already been initialized for `{:?}` with strict initialization enabled",
+                    key, schedule
+                );
+            }
+            return false;
+        }
+        labels.keys.insert((key, schedule));
+        trace!("Initialized `{:?}` for `{:?}`", key, schedule);
+        add_systems(world, key, schedule, systems);
+        true
+    }
+
+    /// Initialize the `systems` if they hasn't been initialized for the already interned `schedule` yet.
+    ///
+    /// This skips interning the label, which is useful when initializing systems for many labels in a loop.
+    /// Shares its marker with [`Initialized::init_systems`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// fn my_system() {
+    ///     // do stuff
+    /// }
+    ///
+    /// let labels = [Update.intern(), PostUpdate.intern()];
+    /// for label in labels {
+    ///     assert!(Initialized::init_systems_interned(&mut world, label, my_system));
+    /// }
+    ///
+    /// assert!(!Initialized::init_systems_interned(&mut world, labels[0], my_system));
+    /// ```
+    #[track_caller]
+    pub fn init_systems_interned<S, Marker>(
+        world: &mut World,
+        schedule: InternedScheduleLabel,
+        systems: S,
+    ) -> bool
+    where
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        init_systems_keyed::<((), S), _>(world, schedule, systems, true, None)
+    }
+
+    /// Initialize the `systems` if they hasn't been initialized for the `schedule` yet, without checking if the `schedule` is run.
+    ///
+    /// Same as [`Initialized::init_systems`] but never warns about creating a schedule that isn't run by bevy,
+    /// which is useful for schedules that are run manually with [`World::run_schedule`].
+    /// Shares its marker with [`Initialized::init_systems`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+    /// struct MySchedule;
+    ///
+    /// fn my_system() {
+    ///     // do stuff
+    /// }
+    ///
+    /// assert!(Initialized::init_systems_unchecked(&mut world, MySchedule, my_system));
+    /// assert!(!Initialized::init_systems(&mut world, MySchedule, my_system));
+    ///
+    /// world.run_schedule(MySchedule);
+    /// ```
+    #[track_caller]
+    pub fn init_systems_unchecked<L, S, Marker>(world: &mut World, schedule: L, systems: S) -> bool
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        init_systems_keyed::<((), S), _>(world, schedule.intern(), systems, false, None)
+    }
+
+    /// Same as [`Initialized::init_systems`] but the systems are logged with a human readable `name`.
+    ///
+    /// The first time the systems are initialized they are recorded in the [`InitRegistry`] with the `name`,
+    /// so it shows up in [`Initialized::list_initialized`] instead of the type name of the systems.
+    /// The `name` is not part of the marker, the systems are still deduplicated with [`Initialized::init_systems`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// assert!(Initialized::init_named_systems(&mut world, "spawn enemies", Update, || {}));
+    ///
+    /// assert_eq!(Initialized::list_initialized(&world), ["spawn enemies"]);
+    /// ```
+    #[track_caller]
+    pub fn init_named_systems<L, S, Marker>(
+        world: &mut World,
+        name: &'static str,
+        schedule: L,
+        systems: S,
+    ) -> bool
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        init_systems_keyed::<((), S), _>(world, schedule.intern(), systems, true, Some(name))
+    }
+
+    /// Initialize the `systems` for each of the `schedules` they hasn't been initialized for yet.
+    ///
+    /// Returns `true` if the systems were added to any of the `schedules`, `false` otherwise.
+    ///
+    /// The `systems` are cloned for every schedule, so they must implement [`Clone`], which is the case for plain function systems.
+    /// Each schedule is initialized with [`Initialized::init_systems_interned`], so calling this again is idempotent per schedule.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// fn my_system() {
+    ///     // do stuff
+    /// }
+    ///
+    /// let schedules = [Update.intern(), FixedUpdate.intern()];
+    /// assert!(Initialized::init_systems_in(&mut world, schedules, my_system));
+    /// assert!(!Initialized::init_systems_in(&mut world, schedules, my_system));
+    /// ```
+    #[track_caller]
+    pub fn init_systems_in<S, Marker>(
+        world: &mut World,
+        schedules: impl IntoIterator<Item = InternedScheduleLabel>,
+        systems: S,
+    ) -> bool
+    where
+        S: IntoSystemConfigs<Marker> + Clone + Send + Sync + 'static,
+    {
+        let mut added = false;
+        for schedule in schedules {
+            added |= Initialized::init_systems_interned(world, schedule, systems.clone());
+        }
+        added
+    }
+
+    /// Initialize the already boxed `configs` if they hasn't been initialized for the `schedule` with the `key` yet.
+    ///
+    /// Returns `true` if the systems were added, `false` otherwise.
+    ///
+    /// Unlike [`Initialized::init_systems`] the marker can't be derived from the type of the systems,
+    /// so the caller supplies the `key` instead, like a `&'static str` or [`TypeId`].
+    /// The marker includes the value of the `schedule` label, different keys of the same type are initialized separately.
+    ///
+    /// This is useful for systems built at runtime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// fn my_system() {
+    ///     // do stuff
+    /// }
+    ///
+    /// let configs = || my_system.into_configs();
+    ///
+    /// assert!(Initialized::init_systems_boxed(&mut world, Update, "my_system", configs()));
+    /// assert!(!Initialized::init_systems_boxed(&mut world, Update, "my_system", configs()));
+    /// ```
+    #[track_caller]
+    pub fn init_systems_boxed<L, K>(
+        world: &mut World,
+        schedule: L,
+        key: K,
+        configs: SystemConfigs,
+    ) -> bool
+    where
+        L: ScheduleLabel,
+        K: Hash + Eq + Send + Sync + 'static,
+    {
+        let label = schedule.intern();
+        let mut boxed = world.get_resource_or_insert_with(BoxedKeys::default);
+        let inserted = boxed
+            .keys
+            .entry(TypeId::of::<K>())
+            .or_insert_with(|| Box::<HashSet<(K, InternedScheduleLabel)>>::default())
+            .downcast_mut::<HashSet<(K, InternedScheduleLabel)>>()
+            .unwrap()
+            .insert((key, label));
+        if !inserted {
+            return false;
+        }
+        let id = boxed.next_id;
+        boxed.next_id += 1;
+        trace!(
+            "Initialized `{}` key for `{:?}`",
+            std::any::type_name::<K>(),
+            label
+        );
+        add_systems(world, RegistrationKey::Boxed(id), label, configs);
+        true
+    }
+
+    /// Same as [`Initialized::init_systems`] but also skips the `systems` if all of them already exist in the `schedule`.
+    ///
+    /// This fixes the quirk where a system added with `add_systems` is added again by [`Initialized::init_systems`].
+    /// The `systems` are compared by type so closures, which have unique types, are always added.
+    ///
+    /// Returns `true` if the systems were added, `false` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut app = App::new();
+    /// #
+    /// fn my_system() {
+    ///     // do stuff
+    /// }
+    ///
+    /// app.add_systems(Update, my_system);
+    ///
+    /// // `my_system` already exists in `Update` so it's not added again
+    /// assert!(!Initialized::init_systems_distinct(&mut app.world, Update, my_system));
+    /// ```
+    #[track_caller]
+    pub fn init_systems_distinct<L, S, Marker>(world: &mut World, schedule: L, systems: S) -> bool
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Clone + Send + Sync + 'static,
+    {
+        if init_labeled::<SystemMarker<((), S)>>(world, schedule.intern()) {
+            let mut incoming = Schedule::default();
+            incoming.add_systems(systems.clone());
+            let incoming: Option<Vec<TypeId>> = incoming
+                .graph()
+                .systems()
+                .map(|(_, system, _)| (!is_closure(system)).then(|| system.type_id()))
+                .collect();
+
+            if let (Some(incoming), Some(schedule)) = (
+                incoming,
+                world
+                    .get_resource::<Schedules>()
+                    .and_then(|schedules| schedules.get(schedule.intern())),
+            ) {
+                let existing = system_type_ids(schedule);
+                if incoming.iter().all(|id| existing.contains(id)) {
+                    trace!(
+                        "Skipped `{}` since the systems already exist in the schedule",
+                        std::any::type_name::<S>()
+                    );
+                    return false;
+                }
+            }
+
+            add_systems(
+                world,
+                TypeId::of::<SystemMarker<((), S)>>(),
+                schedule.intern(),
+                systems,
+            );
+            return true;
+        }
+        false
+    }
+
+    /// Same as [`Initialized::init_systems`] but returns the ids of the added systems.
+    ///
+    /// Returns `Some` with the [`NodeId`] of every system in `systems` if they were initialized, `None` otherwise.
+    ///
+    /// Note that systems in a [`Schedule`] are identified by their [`NodeId`],
+    /// [`SystemId`](bevy_ecs::system::SystemId) only refers to one-shot systems registered with [`World::register_system`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// # world.init_resource::<Schedules>();
+    /// #
+    /// fn sys1() {}
+    /// fn sys2() {}
+    ///
+    /// let ids = Initialized::init_systems_with_id(&mut world, Update, (sys1, sys2));
+    /// assert_eq!(ids.map(|ids| ids.len()), Some(2));
+    ///
+    /// assert!(Initialized::init_systems_with_id(&mut world, Update, (sys1, sys2)).is_none());
+    /// ```
+    #[track_caller]
+    pub fn init_systems_with_id<L, S, Marker>(
+        world: &mut World,
+        schedule: L,
+        systems: S,
+    ) -> Option<Vec<NodeId>>
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        if init_labeled::<SystemMarker<((), S)>>(world, schedule.intern()) {
+            return Some(add_systems(
+                world,
+                TypeId::of::<SystemMarker<((), S)>>(),
+                schedule.intern(),
+                systems,
+            ));
+        }
+        None
+    }
+
+    /// Same as [`Initialized::init_systems`] but applies `.after(set)` to the `systems` before adding them.
+    ///
+    /// The `set` is not part of the marker, so calling this again with a different `set` doesn't add the systems twice.
+    /// This shares its marker with [`Initialized::init_systems`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut app = App::new();
+    /// #
+    /// fn setup() {}
+    ///
+    /// fn my_system() {
+    ///     // runs after `setup`
+    /// }
+    ///
+    /// app.add_systems(Update, setup);
+    ///
+    /// assert!(Initialized::init_systems_after(&mut app.world, Update, my_system, setup));
+    /// ```
+    #[track_caller]
+    pub fn init_systems_after<L, S, Marker, A, AMarker>(
+        world: &mut World,
+        schedule: L,
+        systems: S,
+        set: A,
+    ) -> bool
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+        A: IntoSystemSet<AMarker>,
+    {
+        if init_labeled::<SystemMarker<((), S)>>(world, schedule.intern()) {
+            add_systems(
+                world,
+                TypeId::of::<SystemMarker<((), S)>>(),
+                schedule.intern(),
+                systems.after(set),
+            );
+            return true;
+        }
+        false
+    }
+
+    /// Same as [`Initialized::init_systems`] but applies `.run_if(condition)` to the `systems` before adding them.
+    ///
+    /// The `condition` is not part of the marker, so this shares its marker with [`Initialized::init_systems`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// #[derive(Resource)]
+    /// struct Enabled;
+    ///
+    /// fn my_system() {
+    ///     // do stuff
+    /// }
+    ///
+    /// assert!(Initialized::init_systems_run_if(
+    ///     &mut world,
+    ///     Update,
+    ///     my_system,
+    ///     resource_exists::<Enabled>,
+    /// ));
+    /// assert!(!Initialized::init_systems(&mut world, Update, my_system));
+    /// ```
+    #[track_caller]
+    pub fn init_systems_run_if<L, S, Marker, C, CMarker>(
+        world: &mut World,
+        schedule: L,
+        systems: S,
+        condition: C,
+    ) -> bool
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+        C: Condition<CMarker>,
+    {
+        if init_labeled::<SystemMarker<((), S)>>(world, schedule.intern()) {
+            add_systems(
+                world,
+                TypeId::of::<SystemMarker<((), S)>>(),
+                schedule.intern(),
+                systems.run_if(condition),
+            );
+            return true;
+        }
+        false
+    }
+
+    /// Removes the `systems` that were added to the `schedule` by [`Initialized::init_systems`] and deinitializes them.
+    ///
+    /// Returns `true` if the systems were initialized, `false` otherwise.
+    ///
+    /// Systems can't be removed from a [`Schedule`] so they stay in the schedule but never run again.
+    /// Initializing the same systems again adds new copies of them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// fn my_system() {
+    ///     // do stuff
+    /// }
+    ///
+    /// assert!(Initialized::init_systems(&mut world, Update, my_system));
+    ///
+    /// assert!(Initialized::deinit_systems(&mut world, Update, my_system));
+    /// assert!(!Initialized::deinit_systems(&mut world, Update, my_system));
+    ///
+    /// // can be initialized again
+    /// assert!(Initialized::init_systems(&mut world, Update, my_system));
+    /// ```
+    pub fn deinit_systems<L, S, Marker>(world: &mut World, schedule: L, _systems: S) -> bool
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        deinit_systems_keyed::<((), S)>(world, schedule.intern())
+    }
+
+    /// Same as [`Initialized::init_systems`] but returns a closure that undoes the registration if the systems were added.
+    ///
+    /// Calling the closure disables exactly the systems added by this call and deinitializes them like [`Initialized::deinit_systems`],
+    /// so they can be initialized again. If the systems have already been deinitialized and initialized again in the meantime,
+    /// the new copies are left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// fn my_system() {
+    ///     // do stuff
+    /// }
+    ///
+    /// let undo = Initialized::init_systems_undoable(&mut world, Update, my_system).unwrap();
+    /// assert!(Initialized::init_systems_undoable(&mut world, Update, my_system).is_none());
+    ///
+    /// undo(&mut world);
+    /// assert!(!Initialized::systems_initialized(&world, Update, my_system));
+    /// ```
+    #[must_use]
+    #[track_caller]
+    pub fn init_systems_undoable<L, S, Marker>(
+        world: &mut World,
+        schedule: L,
+        systems: S,
+    ) -> Option<impl FnOnce(&mut World) + Send + Sync + 'static>
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        let label = schedule.intern();
+        if !Initialized::init_systems(world, label, systems) {
+            return None;
+        }
+        let key = (TypeId::of::<SystemMarker<((), S)>>().into(), label);
+        let enabled = world.resource::<Registrations>().0[&key].enabled.clone();
+        Some(move |world: &mut World| {
+            let current = world
+                .get_resource::<Registrations>()
+                .and_then(|registrations| registrations.0.get(&key))
+                .is_some_and(|registration| Arc::ptr_eq(&registration.enabled, &enabled));
+            if current {
+                deinit_systems_keyed::<((), S)>(world, label);
+            } else {
+                enabled.store(false, Ordering::Relaxed);
+            }
+        })
+    }
+
+    /// Returns `true` if the `systems` have been initialized for the `schedule` by [`Initialized::init_systems`].
+    ///
+    /// Unlike [`Initialized::init_systems`] the `world` isn't modified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// fn my_system() {
+    ///     // do stuff
+    /// }
+    ///
+    /// assert!(!Initialized::systems_initialized(&world, Update, my_system));
+    ///
+    /// Initialized::init_systems(&mut world, Update, my_system);
+    /// assert!(Initialized::systems_initialized(&world, Update, my_system));
+    /// assert!(!Initialized::systems_initialized(&world, PostUpdate, my_system));
+    /// ```
+    #[must_use]
+    pub fn systems_initialized<L, S, Marker>(world: &World, schedule: L, _systems: S) -> bool
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        world
+            .get_resource::<InitializedLabels>()
+            .is_some_and(|labels| {
+                labels
+                    .keys
+                    .contains(&(TypeId::of::<SystemMarker<((), S)>>(), schedule.intern()))
+            })
+    }
+
+    /// Inserts an empty [`Schedule`] for the `label` if it hasn't been initialized yet.
+    ///
+    /// Returns `true` if the schedule was not previously initialized, `false` otherwise.
+    ///
+    /// The [`Schedules`] resource is inserted if it does not exist in the `world` yet.
+    /// If a schedule with the `label` already exists it's left untouched but the marker is still initialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+    /// struct MySchedule;
+    ///
+    /// assert!(Initialized::init_schedule(&mut world, MySchedule));
+    /// assert!(!Initialized::init_schedule(&mut world, MySchedule));
+    ///
+    /// assert!(world.resource::<Schedules>().contains(MySchedule));
+    /// ```
+    #[track_caller]
+    pub fn init_schedule<L: ScheduleLabel>(world: &mut World, label: L) -> bool {
+        if Initialized::<ScheduleMarker<L>>::init(world) {
+            let mut schedules = world.get_resource_or_insert_with::<Schedules>(Default::default);
+            if !schedules.contains(label.intern()) {
+                schedules.insert(Schedule::new(label));
+            }
+            return true;
+        }
+        false
+    }
+
+    /// Configures the `sets` if they hasn't been configured for the `schedule` yet.
+    ///
+    /// Returns `true` if the sets were configured, `false` otherwise.
+    ///
+    /// The marker includes the value of the `schedule` label, so the same `sets` can be configured once in every schedule.
+    /// The [`Schedules`] resource is inserted if it does not exist in the `world` yet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    /// enum MySet {
+    ///     A,
+    ///     B,
+    /// }
+    ///
+    /// assert!(Initialized::init_sets(&mut world, Update, MySet::A.before(MySet::B)));
+    /// assert!(!Initialized::init_sets(&mut world, Update, MySet::A.before(MySet::B)));
+    ///
+    /// assert!(Initialized::init_sets(&mut world, FixedUpdate, MySet::A.before(MySet::B)));
+    /// ```
+    #[track_caller]
+    pub fn init_sets<L, Sets>(world: &mut World, schedule: L, sets: Sets) -> bool
+    where
+        L: ScheduleLabel,
+        Sets: IntoSystemSetConfigs + 'static,
+    {
+        let label = schedule.intern();
+        if init_labeled::<SetsMarker<Sets>>(world, label) {
+            let mut schedules = world.get_resource_or_insert_with::<Schedules>(Default::default);
+            if !schedules.contains(label) {
+                schedules.insert(Schedule::new(label));
+            }
+            schedules.get_mut(label).unwrap().configure_sets(sets);
+            return true;
+        }
+        false
+    }
+
+    /// Applies the build `settings` to the schedule with the `label` if they hasn't been applied yet.
+    ///
+    /// Returns `true` if the settings were applied, `false` otherwise.
+    ///
+    /// The marker includes the value of the `label` and the schedule is created if it doesn't exist.
+    /// Settings applied to the schedule by other means can't be detected, so the first call always overwrites them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::{ecs::schedule::{LogLevel, ScheduleBuildSettings}, prelude::*};
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// let settings = ScheduleBuildSettings {
+    ///     ambiguity_detection: LogLevel::Warn,
+    ///     ..default()
+    /// };
+    ///
+    /// assert!(Initialized::init_schedule_settings(&mut world, Update, settings.clone()));
+    /// assert!(!Initialized::init_schedule_settings(&mut world, Update, settings));
+    /// ```
+    #[track_caller]
+    pub fn init_schedule_settings<L: ScheduleLabel>(
+        world: &mut World,
+        label: L,
+        settings: ScheduleBuildSettings,
+    ) -> bool {
+        let label = label.intern();
+        if init_labeled::<SettingsMarker>(world, label) {
+            let mut schedules = world.get_resource_or_insert_with::<Schedules>(Default::default);
+            if !schedules.contains(label) {
+                schedules.insert(Schedule::new(label));
+            }
+            schedules
+                .get_mut(label)
+                .unwrap()
+                .set_build_settings(settings);
+            return true;
+        }
+        false
+    }
+
+    /// Inserts `value` as the resource `R` if it hasn't been initialized through this method yet.
+    ///
+    /// Returns `true` if `Initialized<R>` was not previously initialized, `false` otherwise.
+    ///
+    /// This is keyed on the `Initialized<R>` marker. If `R` was already inserted by other means
+    /// the existing resource is left untouched but the marker is still initialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// #[derive(Resource)]
+    /// struct Score(u32);
+    ///
+    /// assert!(Initialized::init_resource_value(&mut world, Score(10)));
+    /// assert!(!Initialized::init_resource_value(&mut world, Score(20)));
+    ///
+    /// assert_eq!(world.resource::<Score>().0, 10);
+    /// ```
+    #[track_caller]
+    pub fn init_resource_value<R: Resource>(world: &mut World, value: R) -> bool {
+        if Initialized::<R>::init(world) {
+            if !world.contains_resource::<R>() {
+                world.insert_resource(value);
+            }
+            return true;
+        }
+        false
+    }
+
+    /// Initializes the resource `R` and adds its `systems` to the `schedule` if they haven't been initialized yet.
+    ///
+    /// Returns `true` if the resource and systems were initialized, `false` otherwise.
+    ///
+    /// The resource and systems share a single marker so one is never initialized without the other.
+    /// If `R` was already inserted by other means the existing resource is left untouched but the systems are still added.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// #[derive(Resource, Default)]
+    /// struct Score(u32);
+    ///
+    /// fn update_score(mut score: ResMut<Score>) {
+    ///     score.0 += 1;
+    /// }
+    ///
+    /// fn reset_score(mut score: ResMut<Score>) {
+    ///     score.0 = 0;
+    /// }
+    ///
+    /// assert!(Initialized::init_resource_systems::<Score, _, _, _>(
+    ///     &mut world,
+    ///     Update,
+    ///     (update_score, reset_score)
+    /// ));
+    /// assert!(!Initialized::init_resource_systems::<Score, _, _, _>(
+    ///     &mut world,
+    ///     Update,
+    ///     (update_score, reset_score)
+    /// ));
+    /// ```
+    #[track_caller]
+    pub fn init_resource_systems<R, L, S, Marker>(
+        world: &mut World,
+        schedule: L,
+        systems: S,
+    ) -> bool
+    where
+        R: Resource + Default,
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker>,
+    {
+        // the marker is recorded for the schedule so `clear_all` disables the systems, but is shared by every schedule
+        if is_labeled_anywhere::<ResourceSystemsMarker<R>>(world) {
+            if Initialized::is_strict(world) {
+                panic!(
+                    "`{}` has already been initialized with strict initialization enabled",
+                    std::any::type_name::<ResourceSystemsMarker<R>>()
+                );
+            }
+            return false;
+        }
+        let label = schedule.intern();
+        init_labeled::<ResourceSystemsMarker<R>>(world, label);
+        world.init_resource::<R>();
+        add_systems(
+            world,
+            TypeId::of::<ResourceSystemsMarker<R>>(),
+            label,
+            systems,
+        );
+        true
+    }
+}
+
+/// The marker used by [`Initialized::init_resource_systems`].
+struct ResourceSystemsMarker<R>(PhantomData<R>);
+
+/// The marker used by [`Initialized::init_systems`] and its variants, keyed on `(M, S)` and the schedule label.
+///
+/// This type is private so the marker can't be initialized outside of this crate,
+/// which would prevent the systems from being added.
+struct SystemMarker<K>(PhantomData<K>);
+
+/// The marker used by [`Initialized::init_schedule`].
+struct ScheduleMarker<L>(PhantomData<L>);
+
+/// The marker used by [`Initialized::init_sets`].
+struct SetsMarker<S>(PhantomData<S>);
+
+/// The marker used by [`Initialized::init_schedule_settings`].
+struct SettingsMarker;
+
+/// The keys initialized by [`init_labeled`], keyed on a type and schedule label.
+///
+/// Also holds the flag set by [`Initialized::set_strict`] so the fast path of [`init_systems_keyed`] stays a single lookup.
+#[derive(Resource, Default)]
+struct InitializedLabels {
+    keys: HashSet<(TypeId, InternedScheduleLabel)>,
+    strict: bool,
+}
+
+/// Initializes the marker `K` for the `schedule` if it hasn't been initialized yet.
+///
+/// Unlike [`Initialized::init`] this is keyed on the value of the `schedule` label rather than its type.
+fn init_labeled<K: 'static>(world: &mut World, schedule: InternedScheduleLabel) -> bool {
+    init_labeled_as::<K>(world, schedule, std::any::type_name::<K>())
+}
+
+/// Same as [`init_labeled`] but logs the marker as `name`.
+fn init_labeled_as<K: 'static>(
+    world: &mut World,
+    schedule: InternedScheduleLabel,
+    _name: &str,
+) -> bool {
+    let mut labels = world.get_resource_or_insert_with(InitializedLabels::default);
+    let inserted = labels.keys.insert((TypeId::of::<K>(), schedule));
+    if inserted {
+        trace!("Initialized `{}` for `{:?}`", _name, schedule);
+    }
+    inserted
+}
+
+/// Returns `true` if the marker `K` is initialized for any schedule.
+fn is_labeled_anywhere<K: 'static>(world: &World) -> bool {
+    let type_id = TypeId::of::<K>();
+    world
+        .get_resource::<InitializedLabels>()
+        .is_some_and(|labels| labels.keys.iter().any(|(id, _)| *id == type_id))
+}
+
+/// The systems added by [`add_systems`], keyed on the marker and schedule label.
+#[derive(Resource, Default)]
+struct Registrations(HashMap<(RegistrationKey, InternedScheduleLabel), Registration>);
+
+/// The key a call to [`add_systems`] is recorded under.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum RegistrationKey {
+    /// The [`TypeId`] of a marker.
+    Type(TypeId),
+    /// The hash of [`DedupBy`].
+    Hash(u64),
+    /// The id of a key in [`BoxedKeys`].
+    Boxed(u64),
+}
+
+impl From<TypeId> for RegistrationKey {
+    fn from(type_id: TypeId) -> Self {
+        Self::Type(type_id)
+    }
+}
+
+/// A single call to [`add_systems`].
+struct Registration {
+    systems: Vec<NodeId>,
+    enabled: Arc<AtomicBool>,
+}
+
+/// The keys initialized by [`Initialized::init_systems_boxed`].
+///
+/// The keys are stored by the [`TypeId`] of their type, each value is a `HashSet<(K, InternedScheduleLabel)>`.
+/// Every key is given an id to record its systems under in [`Registrations`].
+#[derive(Resource, Default)]
+struct BoxedKeys {
+    keys: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+    next_id: u64,
+}
+
+/// Deinitializes every key of [`Initialized::init_systems_boxed`] and disables their systems,
+/// returning the number of keys that were removed.
+fn clear_boxed_keys(world: &mut World) -> usize {
+    if world.remove_resource::<BoxedKeys>().is_none() {
+        return 0;
+    }
+    let Some(mut registrations) = world.get_resource_mut::<Registrations>() else {
+        return 0;
+    };
+    let mut removed = 0;
+    registrations.0.retain(|(key, _), registration| {
+        let boxed = matches!(key, RegistrationKey::Boxed(_));
+        if boxed {
+            registration.enabled.store(false, Ordering::Relaxed);
+            removed += 1;
+        }
+        !boxed
+    });
+    removed
+}
+
+/// Deinitializes the marker `K` for the `schedule`.
+///
+/// Returns `true` if the marker was previously initialized, `false` otherwise.
+fn deinit_labeled<K: 'static>(world: &mut World, schedule: InternedScheduleLabel) -> bool {
+    let removed = world
+        .get_resource_mut::<InitializedLabels>()
+        .is_some_and(|mut labels| labels.keys.remove(&(TypeId::of::<K>(), schedule)));
+    if removed {
+        trace!(
+            "Deinitialized `{}` for `{:?}`",
+            std::any::type_name::<K>(),
+            schedule
+        );
+    }
+    removed
+}
+
+/// Initialize the `systems` if they hasn't been initialized for the `schedule` with the key `K` yet.
+///
+/// The systems are recorded under `SystemMarker<K>` so they can be disabled by [`remove_systems`].
+/// If `check` is `true` a warning is logged when creating a schedule that isn't run by bevy.
+/// If a `name` is given the systems are logged and recorded in the [`InitRegistry`] with it.
+#[track_caller]
+fn init_systems_keyed<K: 'static, Marker>(
+    world: &mut World,
+    schedule: InternedScheduleLabel,
+    systems: impl IntoSystemConfigs<Marker>,
+    check: bool,
+    name: Option<&'static str>,
+) -> bool {
+    // fast path for call sites that run every frame, `Schedules` is never touched
+    if let Some(labels) = world.get_resource::<InitializedLabels>() {
+        if labels
+            .keys
+            .contains(&(TypeId::of::<SystemMarker<K>>(), schedule))
+        {
+            if labels.strict {
+                panic!(
+                    "`{}` has already been initialized for `{:?}` with strict initialization enabled",
+                    name.unwrap_or(std::any::type_name::<K>()),
+                    schedule
+                );
+            }
+            return false;
+        }
+    }
+    match name {
+        Some(name) => {
+            if !is_labeled_anywhere::<SystemMarker<K>>(world) {
+                InitRegistry::record_named(
+                    world,
+                    TypeId::of::<SystemMarker<K>>(),
+                    name,
+                    Location::caller(),
+                    None,
+                    deinit_named::<SystemMarker<K>>,
+                );
+            }
+            init_labeled_as::<SystemMarker<K>>(world, schedule, name);
+        }
+        None => {
+            init_labeled::<SystemMarker<K>>(world, schedule);
+        }
+    }
+    // covers creating the schedule and adding the systems, so the cost shows up in profilers
+    trace_span!(
+        "init_systems",
+        marker = name.unwrap_or(std::any::type_name::<K>()),
+        schedule = ?schedule
+    );
+    #[cfg(feature = "log")]
+    if check
+        && !world
+            .get_resource::<Schedules>()
+            .is_some_and(|schedules| schedules.contains(schedule))
+        && !is_known_schedule(world, schedule)
+    {
+        bevy_log::warn!(
+            "Created schedule `{:?}` which isn't run by bevy, the systems may never be executed. \
+            Use `Initialized::init_systems_unchecked` if the schedule is run manually",
+            schedule
+        );
+    }
+    #[cfg(not(feature = "log"))]
+    let _ = check;
+    #[cfg(feature = "debug_checks")]
+    let existing = debug_checks::existing_systems(world, schedule);
+    let _added = add_systems(world, TypeId::of::<SystemMarker<K>>(), schedule, systems);
+    #[cfg(feature = "debug_checks")]
+    debug_checks::check_duplicates(world, schedule, existing, &_added);
+    true
+}
+
+/// Deinitializes and disables the systems added by [`init_systems_keyed`] with the marker `K` for the `schedule`.
+fn deinit_systems_keyed<K: 'static>(world: &mut World, schedule: InternedScheduleLabel) -> bool {
+    if deinit_labeled::<SystemMarker<K>>(world, schedule) {
+        remove_systems(world, TypeId::of::<SystemMarker<K>>(), schedule);
+        if !is_labeled_anywhere::<SystemMarker<K>>(world) {
+            InitRegistry::forget_type_id(world, TypeId::of::<SystemMarker<K>>());
+        }
+        return true;
+    }
+    false
+}
+
+/// Deinitializes the named systems with the marker `K` for every schedule, see [`Initialized::init_named_systems`].
+fn deinit_named<K: 'static>(world: &mut World) -> bool {
+    let type_id = TypeId::of::<K>();
+    InitRegistry::forget_type_id(world, type_id);
+    let Some(mut labels) = world.get_resource_mut::<InitializedLabels>() else {
+        return false;
+    };
+    let removed: Vec<_> = labels
+        .keys
+        .iter()
+        .filter(|(id, _)| *id == type_id)
+        .copied()
+        .collect();
+    for key in &removed {
+        labels.keys.remove(key);
+    }
+    for (_, schedule) in &removed {
+        remove_systems(world, type_id, *schedule);
+    }
+    !removed.is_empty()
+}
+
+/// Returns `true` if the `schedule` is run by bevy's [`Main`](bevy_app::Main) schedule.
+///
+/// This includes the schedules in [`MainScheduleOrder`](bevy_app::MainScheduleOrder)
+/// and [`FixedMainScheduleOrder`](bevy_app::FixedMainScheduleOrder) if they exist in the `world`.
+#[cfg(feature = "log")]
+fn is_known_schedule(world: &World, schedule: InternedScheduleLabel) -> bool {
+    use bevy_app::{
+        First, FixedFirst, FixedLast, FixedMain, FixedMainScheduleOrder, FixedPostUpdate,
+        FixedPreUpdate, FixedUpdate, Last, Main, MainScheduleOrder, PostStartup, PostUpdate,
+        PreStartup, PreUpdate, RunFixedMainLoop, SpawnScene, Startup, StateTransition, Update,
+    };
+
+    let known = [
+        Main.intern(),
+        PreStartup.intern(),
+        Startup.intern(),
+        PostStartup.intern(),
+        First.intern(),
+        PreUpdate.intern(),
+        StateTransition.intern(),
+        RunFixedMainLoop.intern(),
+        FixedMain.intern(),
+        FixedFirst.intern(),
+        FixedPreUpdate.intern(),
+        FixedUpdate.intern(),
+        FixedPostUpdate.intern(),
+        FixedLast.intern(),
+        Update.intern(),
+        SpawnScene.intern(),
+        PostUpdate.intern(),
+        Last.intern(),
+    ];
+    known.contains(&schedule)
+        || world
+            .get_resource::<MainScheduleOrder>()
+            .is_some_and(|order| {
+                order.labels.contains(&schedule) || order.startup_labels.contains(&schedule)
+            })
+        || world
+            .get_resource::<FixedMainScheduleOrder>()
+            .is_some_and(|order| order.labels.contains(&schedule))
+}
+
+/// Adds the `systems` to the `schedule`, creating the schedule if it doesn't exist.
+///
+/// The systems are recorded under the `key` so they can be disabled by [`remove_systems`] later.
+///
+/// Returns the ids of the added systems.
+#[track_caller]
+fn add_systems<Marker>(
+    world: &mut World,
+    key: impl Into<RegistrationKey>,
+    schedule: InternedScheduleLabel,
+    systems: impl IntoSystemConfigs<Marker>,
+) -> Vec<NodeId> {
+    let enabled = Arc::new(AtomicBool::new(true));
+    let condition = {
+        let enabled = enabled.clone();
+        move || enabled.load(Ordering::Relaxed)
+    };
+
+    let mut schedules = world.get_resource_or_insert_with::<Schedules>(Default::default);
+    if !schedules.contains(schedule) {
+        trace!("Created schedule `{:?}`", schedule);
+        schedules.insert(Schedule::new(schedule));
+    }
+    let label = schedule;
+    let schedule = schedules.get_mut(label).unwrap();
+
+    let existing: HashSet<NodeId> = system_ids(schedule).collect();
+    schedule.add_systems(systems.run_if(condition));
+    let systems: Vec<NodeId> = system_ids(schedule)
+        .filter(|id| !existing.contains(id))
+        .collect();
+
+    world
+        .get_resource_or_insert_with(Registrations::default)
+        .0
+        .insert(
+            (key.into(), label),
+            Registration {
+                systems: systems.clone(),
+                enabled,
+            },
+        );
+    systems
+}
+
+/// Disables the systems recorded under the `key` by [`add_systems`].
+///
+/// Systems can't be removed from a [`Schedule`], so they are disabled by a run condition instead.
+///
+/// Returns the ids of the disabled systems.
+fn remove_systems(
+    world: &mut World,
+    key: impl Into<RegistrationKey>,
+    schedule: InternedScheduleLabel,
+) -> Option<Vec<NodeId>> {
+    let registration = world
+        .get_resource_mut::<Registrations>()?
+        .0
+        .remove(&(key.into(), schedule))?;
+    registration.enabled.store(false, Ordering::Relaxed);
+    Some(registration.systems)
+}
+
+/// Disables the systems recorded under the `key` by [`add_systems`] for every schedule.
+fn remove_systems_anywhere(world: &mut World, key: impl Into<RegistrationKey>) {
+    let key = key.into();
+    let Some(mut registrations) = world.get_resource_mut::<Registrations>() else {
+        return;
+    };
+    registrations.0.retain(|(k, _), registration| {
+        if *k == key {
+            registration.enabled.store(false, Ordering::Relaxed);
+        }
+        *k != key
+    });
+}
+
+/// Returns the [`TypeId`] of every system in the `schedule`.
+fn system_type_ids(schedule: &Schedule) -> HashSet<TypeId> {
+    let graph = schedule
+        .graph()
+        .systems()
+        .map(|(_, system, _)| system.type_id());
+    match schedule.systems() {
+        Ok(built) => graph
+            .chain(built.map(|(_, system)| system.as_ref().type_id()))
+            .collect(),
+        Err(_) => graph.collect(),
+    }
+}
+
+/// Returns `true` if the `system` is a closure, closures have unique types so they can't be compared by type.
+fn is_closure(system: &dyn System<In = (), Out = ()>) -> bool {
+    system.name().contains("{{closure}}")
+}
+
+/// Returns the ids of every system that has been added to the `schedule`.
+fn system_ids(schedule: &Schedule) -> impl Iterator<Item = NodeId> + '_ {
+    schedule
+        .graph()
+        .hierarchy()
+        .graph()
+        .nodes()
+        .filter(NodeId::is_system)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_zst_marker, system_ids, DynInitialized, InitError, Initialized};
+    use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+
+    #[test]
+    fn test_init() {
+        let mut world = World::new();
+        assert!(Initialized::<()>::init(&mut world));
         assert!(!Initialized::<()>::init(&mut world));
     }
 
     #[test]
-    fn test_init_systems() {
+    fn test_assert_zst_marker() {
+        struct MyMarker;
+
+        const _: () = assert_zst_marker::<MyMarker>();
+        assert_zst_marker::<()>();
+    }
+
+    #[test]
+    #[should_panic = "not zero sized"]
+    fn test_assert_zst_marker_sized() {
+        assert_zst_marker::<u32>();
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn test_init_log() {
+        struct MyMarker;
+
+        let mut world = World::new();
+        assert!(Initialized::<MyMarker>::init_log(
+            &mut world,
+            bevy::log::Level::INFO
+        ));
+        assert!(!Initialized::<MyMarker>::init_log(
+            &mut world,
+            bevy::log::Level::INFO
+        ));
+        assert!(!Initialized::<MyMarker>::init(&mut world));
+    }
+
+    #[test]
+    fn test_init_with() {
+        let mut world = World::new();
+
+        let mut n = 0;
+        for _ in 0..5 {
+            Initialized::<()>::init_with(&mut world, |_| n += 1);
+        }
+        assert_eq!(n, 1);
+    }
+
+    #[test]
+    fn test_init_if() {
+        #[derive(Resource)]
+        struct Ready;
+
+        let mut world = World::new();
+
+        let mut evaluated = 0;
+        let mut ready = |world: &World| {
+            evaluated += 1;
+            world.contains_resource::<Ready>()
+        };
+        assert!(!Initialized::<()>::init_if(&mut world, &mut ready));
+        assert!(!Initialized::<()>::init_if(&mut world, &mut ready));
+        assert!(!Initialized::<()>::is_initialized(&world));
+
+        world.insert_resource(Ready);
+        assert!(Initialized::<()>::init_if(&mut world, &mut ready));
+        assert!(!Initialized::<()>::init_if(&mut world, &mut ready));
+        assert_eq!(evaluated, 3);
+    }
+
+    #[test]
+    fn test_init_run_system() {
+        #[derive(Resource, Default)]
+        struct Counter(usize);
+
+        fn count(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        for _ in 0..5 {
+            Initialized::<()>::init_run_system(&mut world, count);
+        }
+        assert_eq!(world.resource::<Counter>().0, 1);
+    }
+
+    #[test]
+    fn test_is_initialized() {
+        let mut world = World::new();
+        assert!(!Initialized::<()>::is_initialized(&world));
+        assert!(!Initialized::<()>::is_initialized(&world));
+        assert!(Initialized::<()>::init(&mut world));
+        assert!(Initialized::<()>::is_initialized(&world));
+    }
+
+    #[test]
+    fn test_deinit() {
+        let mut world = World::new();
+        assert!(!Initialized::<()>::deinit(&mut world));
+        assert!(Initialized::<()>::init(&mut world));
+        assert!(Initialized::<()>::deinit(&mut world));
+        assert!(!Initialized::<()>::deinit(&mut world));
+        assert!(Initialized::<()>::init(&mut world));
+    }
+
+    #[test]
+    fn test_deinit_with() {
+        let mut world = World::new();
+
+        let mut n = 0;
+        assert!(!Initialized::<()>::deinit_with(&mut world, |_| n += 1));
+        assert_eq!(n, 0);
+
+        assert!(Initialized::<()>::init(&mut world));
+        for _ in 0..5 {
+            Initialized::<()>::deinit_with(&mut world, |_| n += 1);
+        }
+        assert_eq!(n, 1);
+    }
+
+    #[test]
+    fn test_init_systems() {
+        fn sys1() {}
+        fn sys2() {}
+
+        let mut world = World::new();
+        world.init_resource::<Schedules>();
+
+        assert!(Initialized::init_systems(&mut world, Update, sys1));
+        assert!(!Initialized::init_systems(&mut world, Update, sys1));
+
+        assert!(Initialized::init_systems(&mut world, FixedUpdate, sys1));
+        assert!(!Initialized::init_systems(&mut world, FixedUpdate, sys1));
+
+        assert!(Initialized::init_systems(&mut world, Update, (sys1, sys2)));
+        assert!(!Initialized::init_systems(&mut world, Update, (sys1, sys2)));
+    }
+
+    #[test]
+    fn test_init_closure_system() {
+        let mut world = World::new();
+        world.init_resource::<Schedules>();
+
+        let mut n = 0;
+        for _ in 0..2 {
+            if Initialized::init_systems(&mut world, Update, || {}) {
+                n += 1;
+            }
+        }
+        assert_eq!(n, 1);
+    }
+
+    #[test]
+    fn test_init_systems_missing_schedules() {
+        fn sys1() {}
+
+        let mut world = World::new();
+
+        assert!(Initialized::init_systems(&mut world, Update, sys1));
+        assert!(world.resource::<Schedules>().contains(Update));
+        assert!(!Initialized::init_systems_strict(&mut world, Update, sys1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_init_systems_strict_missing_schedules() {
+        fn sys1() {}
+
+        let mut world = World::new();
+        Initialized::init_systems_strict(&mut world, Update, sys1);
+    }
+
+    #[test]
+    fn test_try_init_systems_running_schedule() {
+        #[derive(Resource)]
+        struct Output(Result<bool, InitError>);
+
+        fn sys2() {}
+
+        fn init_during_run(world: &mut World) {
+            let result = Initialized::try_init_systems(world, Update, sys2);
+            world.insert_resource(Output(result));
+        }
+
+        let mut world = World::new();
+        assert!(Initialized::init_systems(
+            &mut world,
+            Update,
+            init_during_run
+        ));
+        world.run_schedule(Update);
+        assert_eq!(
+            world.resource::<Output>().0,
+            Err(InitError::ScheduleFinalized)
+        );
+        assert!(!Initialized::systems_initialized(&world, Update, sys2));
+
+        // works once the schedule is done
+        assert_eq!(
+            Initialized::try_init_systems(&mut world, Update, sys2),
+            Ok(true)
+        );
+    }
+
+    #[test]
+    fn test_try_init_systems() {
         fn sys1() {}
-        fn sys2() {}
 
         let mut world = World::new();
+        assert_eq!(
+            Initialized::try_init_systems(&mut world, Update, sys1),
+            Err(InitError::MissingSchedules)
+        );
+
         world.init_resource::<Schedules>();
+        assert_eq!(
+            Initialized::try_init_systems(&mut world, Update, sys1),
+            Ok(true)
+        );
+        assert_eq!(
+            Initialized::try_init_systems(&mut world, Update, sys1),
+            Ok(false)
+        );
+    }
+
+    #[test]
+    fn test_init_systems_distinct() {
+        fn sys1() {}
+        fn sys2() {}
+
+        let mut app = App::new();
+        app.add_systems(Update, sys1);
+        app.update();
+
+        assert!(!Initialized::init_systems_distinct(
+            &mut app.world,
+            Update,
+            sys1
+        ));
+        assert!(Initialized::init_systems_distinct(
+            &mut app.world,
+            Update,
+            (sys1, sys2)
+        ));
+        assert!(!Initialized::init_systems_distinct(
+            &mut app.world,
+            Update,
+            (sys1, sys2)
+        ));
+
+        let len = |app: &App| {
+            let schedule = app.world.resource::<Schedules>().get(Update).unwrap();
+            system_ids(schedule).count()
+        };
+        assert_eq!(len(&app), 3);
+
+        let closure = || {};
+        app.add_systems(Update, closure);
+        assert!(Initialized::init_systems_distinct(
+            &mut app.world,
+            Update,
+            closure
+        ));
+        assert_eq!(len(&app), 5);
+    }
+
+    #[test]
+    fn test_init_resource_value() {
+        #[derive(Resource)]
+        struct A(u32);
+        #[derive(Resource)]
+        struct B(u32);
+
+        let mut world = World::new();
+
+        assert!(Initialized::init_resource_value(&mut world, A(1)));
+        assert!(!Initialized::init_resource_value(&mut world, A(2)));
+        assert_eq!(world.resource::<A>().0, 1);
+
+        world.insert_resource(B(1));
+        assert!(Initialized::init_resource_value(&mut world, B(2)));
+        assert_eq!(world.resource::<B>().0, 1);
+    }
+
+    #[test]
+    fn test_init_systems_run_if() {
+        #[derive(Resource, Default)]
+        struct Counter(usize);
+
+        fn count(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+
+        let enabled = |counter: Res<Counter>| counter.0 < 2;
+        assert!(Initialized::init_systems_run_if(
+            &mut world, Update, count, enabled
+        ));
+        assert!(!Initialized::init_systems_run_if(
+            &mut world, Update, count, enabled
+        ));
+
+        for _ in 0..5 {
+            world.run_schedule(Update);
+        }
+        assert_eq!(world.resource::<Counter>().0, 2);
+    }
+
+    #[test]
+    fn test_init_schedule() {
+        #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+        struct MySchedule;
+
+        fn sys1() {}
+
+        let mut world = World::new();
+        assert!(Initialized::init_schedule(&mut world, MySchedule));
+        assert!(!Initialized::init_schedule(&mut world, MySchedule));
 
+        let schedule = world.resource::<Schedules>().get(MySchedule).unwrap();
+        assert_eq!(system_ids(schedule).count(), 0);
+
+        // existing schedules are not replaced
         assert!(Initialized::init_systems(&mut world, Update, sys1));
+        assert!(Initialized::init_schedule(&mut world, Update));
+        let schedule = world.resource::<Schedules>().get(Update).unwrap();
+        assert_eq!(system_ids(schedule).count(), 1);
+    }
+
+    #[test]
+    fn test_deinit_systems() {
+        #[derive(Resource, Default)]
+        struct Counter(usize);
+
+        fn count(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+
+        assert!(!Initialized::deinit_systems(&mut world, Update, count));
+
+        assert!(Initialized::init_systems(&mut world, Update, count));
+        world.run_schedule(Update);
+        assert_eq!(world.resource::<Counter>().0, 1);
+
+        assert!(Initialized::deinit_systems(&mut world, Update, count));
+        world.run_schedule(Update);
+        assert_eq!(world.resource::<Counter>().0, 1);
+
+        assert!(Initialized::init_systems(&mut world, Update, count));
+        world.run_schedule(Update);
+        assert_eq!(world.resource::<Counter>().0, 2);
+    }
+
+    #[test]
+    fn test_init_systems_erased() {
+        use std::any::TypeId;
+
+        use bevy::ecs::schedule::{InternedScheduleLabel, SystemConfigs};
+
+        #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Custom;
+
+        struct PhysicsKey;
+        struct RenderKey;
+
+        fn physics() {}
+        fn render() {}
+
+        let mut world = World::new();
+        let registrations = || -> Vec<(InternedScheduleLabel, SystemConfigs, TypeId)> {
+            vec![
+                (
+                    Update.intern(),
+                    physics.into_configs(),
+                    TypeId::of::<PhysicsKey>(),
+                ),
+                (
+                    Custom.intern(),
+                    render.into_configs(),
+                    TypeId::of::<RenderKey>(),
+                ),
+                (
+                    PostUpdate.intern(),
+                    (physics, render).into_configs(),
+                    TypeId::of::<RenderKey>(),
+                ),
+            ]
+        };
+
+        for (label, systems, key) in registrations() {
+            assert!(Initialized::init_systems_erased(
+                &mut world, label, systems, key
+            ));
+        }
+        for (label, systems, key) in registrations() {
+            assert!(!Initialized::init_systems_erased(
+                &mut world, label, systems, key
+            ));
+        }
+
+        let schedules = world.resource::<Schedules>();
+        assert_eq!(schedules.get(Update).unwrap().graph().systems().count(), 1);
+        assert_eq!(schedules.get(Custom).unwrap().graph().systems().count(), 1);
+        assert_eq!(
+            schedules.get(PostUpdate).unwrap().graph().systems().count(),
+            2
+        );
+
+        // not shared with `init_systems`
+        assert!(Initialized::init_systems(&mut world, Update, physics));
+    }
+
+    #[test]
+    fn test_init_systems_and_run() {
+        #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Bootstrap;
+
+        #[derive(Resource, Default)]
+        struct Counter(usize);
+
+        fn count(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+
+        assert!(Initialized::init_systems_and_run(
+            &mut world, Bootstrap, count
+        ));
+        assert_eq!(world.resource::<Counter>().0, 1);
+
+        assert!(!Initialized::init_systems_and_run(
+            &mut world, Bootstrap, count
+        ));
+        assert_eq!(world.resource::<Counter>().0, 1);
+
+        // shares the marker with `init_systems`
+        assert!(!Initialized::init_systems(&mut world, Bootstrap, count));
+    }
+
+    #[test]
+    fn test_init_systems_undoable() {
+        #[derive(Resource, Default)]
+        struct Counter(usize);
+
+        fn count(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+
+        let undo = Initialized::init_systems_undoable(&mut world, Update, count).unwrap();
+        assert!(Initialized::init_systems_undoable(&mut world, Update, count).is_none());
+        world.run_schedule(Update);
+        assert_eq!(world.resource::<Counter>().0, 1);
+
+        undo(&mut world);
+        world.run_schedule(Update);
+        assert_eq!(world.resource::<Counter>().0, 1);
+
+        // can be initialized again
+        let stale = Initialized::init_systems_undoable(&mut world, Update, count).unwrap();
+        assert!(Initialized::deinit_systems(&mut world, Update, count));
+        assert!(Initialized::init_systems(&mut world, Update, count));
+
+        // only undoes its own systems
+        stale(&mut world);
+        assert!(Initialized::systems_initialized(&world, Update, count));
+        world.run_schedule(Update);
+        assert_eq!(world.resource::<Counter>().0, 2);
+    }
+
+    #[test]
+    fn test_ensure_systems() {
+        fn sys1() {}
+
+        let mut world = World::new();
+        Initialized::ensure_systems(&mut world, Update, sys1);
+        Initialized::ensure_systems(&mut world, Update, sys1);
         assert!(!Initialized::init_systems(&mut world, Update, sys1));
+        assert_eq!(
+            world
+                .resource::<Schedules>()
+                .get(Update)
+                .unwrap()
+                .graph()
+                .systems()
+                .count(),
+            1
+        );
+    }
 
-        assert!(Initialized::init_systems(&mut world, FixedUpdate, sys1));
-        assert!(!Initialized::init_systems(&mut world, FixedUpdate, sys1));
+    #[test]
+    fn test_systems_initialized() {
+        fn sys1() {}
+        fn sys2() {}
+
+        let mut world = World::new();
+        assert!(!Initialized::systems_initialized(&world, Update, sys1));
 
         assert!(Initialized::init_systems(&mut world, Update, (sys1, sys2)));
-        assert!(!Initialized::init_systems(&mut world, Update, (sys1, sys2)));
+        // the tuple is its own set of systems
+        assert!(!Initialized::systems_initialized(&world, Update, sys1));
+        assert!(Initialized::systems_initialized(
+            &world,
+            Update,
+            (sys1, sys2)
+        ));
+        assert!(!Initialized::systems_initialized(
+            &world,
+            PostUpdate,
+            (sys1, sys2)
+        ));
+
+        // shares the marker with `init_systems_unchecked`
+        assert!(Initialized::init_systems_unchecked(
+            &mut world, Update, sys1
+        ));
+        assert!(Initialized::systems_initialized(&world, Update, sys1));
+
+        assert!(Initialized::deinit_systems(&mut world, Update, sys1));
+        assert!(!Initialized::systems_initialized(&world, Update, sys1));
     }
 
     #[test]
-    fn test_init_closure_system() {
+    fn test_init_sets() {
+        #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+        struct MySet;
+
+        let mut world = World::new();
+
+        assert!(Initialized::init_sets(&mut world, Update, MySet));
+        assert!(!Initialized::init_sets(&mut world, Update, MySet));
+        assert!(Initialized::init_sets(&mut world, FixedUpdate, MySet));
+        assert!(!Initialized::init_sets(&mut world, FixedUpdate, MySet));
+    }
+
+    #[test]
+    fn test_init_systems_for() {
+        struct MarkerA;
+        struct MarkerB;
+
+        fn sys1() {}
+
         let mut world = World::new();
         world.init_resource::<Schedules>();
 
-        let mut n = 0;
-        for _ in 0..2 {
-            if Initialized::init_systems(&mut world, Update, || {}) {
-                n += 1;
+        assert!(Initialized::init_systems(&mut world, Update, sys1));
+        assert!(Initialized::<MarkerA>::init_systems_for(
+            &mut world, Update, sys1
+        ));
+        assert!(Initialized::<MarkerB>::init_systems_for(
+            &mut world, Update, sys1
+        ));
+
+        assert!(!Initialized::<()>::init_systems_for(
+            &mut world, Update, sys1
+        ));
+        assert!(!Initialized::<MarkerA>::init_systems_for(
+            &mut world, Update, sys1
+        ));
+        assert!(!Initialized::<MarkerB>::init_systems_for(
+            &mut world, Update, sys1
+        ));
+    }
+
+    #[test]
+    fn test_init_systems_with_id() {
+        fn sys1() {}
+        fn sys2() {}
+        fn sys3() {}
+
+        let mut world = World::new();
+        world.init_resource::<Schedules>();
+
+        let ids = Initialized::init_systems_with_id(&mut world, Update, sys1).unwrap();
+        assert_eq!(ids.len(), 1);
+        assert!(Initialized::init_systems_with_id(&mut world, Update, sys1).is_none());
+
+        world.run_schedule(Update);
+
+        let nested = Initialized::init_systems_with_id(&mut world, Update, ((sys1, sys2), sys3));
+        let nested = nested.unwrap();
+        assert_eq!(nested.len(), 3);
+        assert!(nested.iter().all(|id| !ids.contains(id)));
+    }
+
+    #[test]
+    fn test_init_systems_interned() {
+        fn sys1() {}
+
+        let mut world = World::new();
+        assert!(Initialized::init_systems_interned(
+            &mut world,
+            Update.intern(),
+            sys1
+        ));
+        assert!(!Initialized::init_systems_interned(
+            &mut world,
+            Update.intern(),
+            sys1
+        ));
+        assert!(Initialized::init_systems_interned(
+            &mut world,
+            PostUpdate.intern(),
+            sys1
+        ));
+        assert!(world.resource::<Schedules>().contains(PostUpdate));
+    }
+
+    #[test]
+    fn test_init_schedule_settings() {
+        use bevy::ecs::schedule::{LogLevel, ScheduleBuildSettings};
+
+        let mut world = World::new();
+        let settings = ScheduleBuildSettings {
+            ambiguity_detection: LogLevel::Warn,
+            ..default()
+        };
+        assert!(Initialized::init_schedule_settings(
+            &mut world,
+            Update,
+            settings.clone()
+        ));
+        assert!(!Initialized::init_schedule_settings(
+            &mut world,
+            Update,
+            ScheduleBuildSettings::default()
+        ));
+
+        let schedules = world.resource::<Schedules>();
+        let schedule = schedules.get(Update).unwrap();
+        assert!(matches!(
+            schedule.get_build_settings().ambiguity_detection,
+            LogLevel::Warn
+        ));
+
+        assert!(Initialized::init_schedule_settings(
+            &mut world, PostUpdate, settings
+        ));
+    }
+
+    #[test]
+    fn test_init_systems_boxed() {
+        use std::any::TypeId;
+
+        fn sys1() {}
+
+        let mut world = World::new();
+        assert!(Initialized::init_systems_boxed(
+            &mut world,
+            Update,
+            "sys1",
+            sys1.into_configs()
+        ));
+        assert!(!Initialized::init_systems_boxed(
+            &mut world,
+            Update,
+            "sys1",
+            sys1.into_configs()
+        ));
+        assert!(Initialized::init_systems_boxed(
+            &mut world,
+            PostUpdate,
+            "sys1",
+            sys1.into_configs()
+        ));
+        assert!(Initialized::init_systems_boxed(
+            &mut world,
+            Update,
+            TypeId::of::<()>(),
+            sys1.into_configs()
+        ));
+    }
+
+    #[test]
+    fn test_init_systems_boxed_keys() {
+        #[derive(Resource, Default)]
+        struct Counter(usize);
+
+        let add = |n| move |mut counter: ResMut<Counter>| counter.0 += n;
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        assert!(Initialized::init_systems_boxed(
+            &mut world,
+            Update,
+            "a",
+            add(1).into_configs()
+        ));
+        assert!(Initialized::init_systems_boxed(
+            &mut world,
+            Update,
+            "b",
+            add(10).into_configs()
+        ));
+
+        // the keys aren't shared with `DynInitialized`
+        assert!(DynInitialized::init(&mut world, ("a", Update.intern())));
+        assert!(!Initialized::init_systems_boxed(
+            &mut world,
+            Update,
+            "a",
+            add(100).into_configs()
+        ));
+
+        world.run_schedule(Update);
+        assert_eq!(world.resource::<Counter>().0, 11);
+
+        // both keys are disabled, not only the last one
+        assert_eq!(Initialized::clear_all(&mut world), 2);
+        world.run_schedule(Update);
+        assert_eq!(world.resource::<Counter>().0, 11);
+
+        assert!(Initialized::init_systems_boxed(
+            &mut world,
+            Update,
+            "a",
+            add(1).into_configs()
+        ));
+        world.run_schedule(Update);
+        assert_eq!(world.resource::<Counter>().0, 12);
+    }
+
+    #[test]
+    fn test_init_systems_in() {
+        fn sys1() {}
+
+        let mut world = World::new();
+        assert!(Initialized::init_systems_in(
+            &mut world,
+            [Update.intern()],
+            sys1
+        ));
+        assert!(Initialized::init_systems_in(
+            &mut world,
+            [Update.intern(), FixedUpdate.intern()],
+            sys1
+        ));
+        assert!(!Initialized::init_systems_in(
+            &mut world,
+            [Update.intern(), FixedUpdate.intern()],
+            sys1
+        ));
+        assert!(world.resource::<Schedules>().contains(FixedUpdate));
+    }
+
+    #[test]
+    fn test_init_systems_marker_isolated() {
+        fn sys1() {}
+
+        // initializes the marker with the same type as the systems key
+        fn init_key<L: Send + Sync + 'static, S: Send + Sync + 'static>(
+            world: &mut World,
+            _: L,
+            _: S,
+        ) -> bool {
+            Initialized::<((), L, S)>::init(world)
+        }
+
+        let mut world = World::new();
+        assert!(init_key(&mut world, Update, sys1));
+        assert!(Initialized::init_systems(&mut world, Update, sys1));
+    }
+
+    #[test]
+    fn test_init_systems_after() {
+        #[derive(Resource, Default)]
+        struct Order(Vec<&'static str>);
+
+        fn first(mut order: ResMut<Order>) {
+            order.0.push("first");
+        }
+
+        fn second(mut order: ResMut<Order>) {
+            order.0.push("second");
+        }
+
+        fn third(mut order: ResMut<Order>) {
+            order.0.push("third");
+        }
+
+        let mut app = App::new();
+        app.init_resource::<Order>()
+            .add_systems(Update, (first, third));
+        assert!(Initialized::init_systems_after(
+            &mut app.world,
+            Update,
+            second,
+            first
+        ));
+        assert!(!Initialized::init_systems_after(
+            &mut app.world,
+            Update,
+            second,
+            third
+        ));
+        assert!(!Initialized::init_systems(&mut app.world, Update, second));
+
+        app.update();
+        let order = &app.world.resource::<Order>().0;
+        assert_eq!(order.len(), 3);
+        let position = |name| order.iter().position(|n| *n == name).unwrap();
+        assert!(position("first") < position("second"));
+    }
+
+    #[test]
+    fn test_init_resource_systems() {
+        #[derive(Resource, Default)]
+        struct Counter(usize);
+
+        fn count(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        let mut world = World::new();
+        for _ in 0..3 {
+            Initialized::init_resource_systems::<Counter, _, _, _>(&mut world, Update, count);
+        }
+        world.run_schedule(Update);
+        assert_eq!(world.resource::<Counter>().0, 1);
+    }
+
+    #[test]
+    fn test_init_systems_label_value() {
+        #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Level(u32);
+
+        fn sys1() {}
+
+        let mut world = World::new();
+        assert!(Initialized::init_systems(&mut world, Level(1), sys1));
+        assert!(Initialized::init_systems(&mut world, Level(2), sys1));
+        assert!(!Initialized::init_systems(&mut world, Level(1), sys1));
+        assert!(!Initialized::init_systems_interned(
+            &mut world,
+            Level(2).intern(),
+            sys1
+        ));
+
+        let schedules = world.resource::<Schedules>();
+        assert!(schedules.contains(Level(1)));
+        assert!(schedules.contains(Level(2)));
+    }
+
+    #[test]
+    fn test_init_systems_fast_path() {
+        fn sys1() {}
+
+        let mut world = World::new();
+        assert!(Initialized::init_systems(&mut world, Update, sys1));
+
+        // the already initialized case doesn't access `Schedules`
+        world.remove_resource::<Schedules>();
+        assert!(!Initialized::init_systems(&mut world, Update, sys1));
+        assert!(!world.contains_resource::<Schedules>());
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn test_init_systems_span() {
+        use std::sync::{Arc, Mutex};
+
+        use bevy_log::tracing_subscriber::{layer::Context, prelude::*, registry::Registry, Layer};
+        use bevy_utils::tracing::{span, subscriber, Subscriber};
+
+        #[derive(Clone, Default)]
+        struct SpanNames(Arc<Mutex<Vec<&'static str>>>);
+
+        impl<S: Subscriber> Layer<S> for SpanNames {
+            fn on_new_span(
+                &self,
+                attrs: &span::Attributes<'_>,
+                _id: &span::Id,
+                _ctx: Context<'_, S>,
+            ) {
+                self.0.lock().unwrap().push(attrs.metadata().name());
             }
         }
-        assert_eq!(n, 1);
+
+        fn sys1() {}
+
+        let names = SpanNames::default();
+        let mut world = World::new();
+        subscriber::with_default(Registry::default().with(names.clone()), || {
+            assert!(Initialized::init_systems(&mut world, Update, sys1));
+            // already initialized, nothing is added
+            assert!(!Initialized::init_systems(&mut world, Update, sys1));
+        });
+        assert_eq!(*names.0.lock().unwrap(), ["init_systems"]);
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn test_is_known_schedule() {
+        #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+        struct MySchedule;
+
+        let mut world = World::new();
+        assert!(super::is_known_schedule(&world, Update.intern()));
+        assert!(super::is_known_schedule(&world, FixedUpdate.intern()));
+        assert!(!super::is_known_schedule(&world, MySchedule.intern()));
+
+        world.init_resource::<bevy::app::MainScheduleOrder>();
+        world
+            .resource_mut::<bevy::app::MainScheduleOrder>()
+            .insert_after(Update, MySchedule);
+        assert!(super::is_known_schedule(&world, MySchedule.intern()));
+    }
+
+    #[test]
+    fn test_init_systems_unchecked() {
+        #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+        struct MySchedule;
+
+        #[derive(Resource, Default)]
+        struct Counter(usize);
+
+        fn count(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        assert!(Initialized::init_systems_unchecked(
+            &mut world, MySchedule, count
+        ));
+        assert!(!Initialized::init_systems(&mut world, MySchedule, count));
+
+        world.run_schedule(MySchedule);
+        assert_eq!(world.resource::<Counter>().0, 1);
+    }
+
+    #[test]
+    fn test_init_named_systems() {
+        fn sys1() {}
+
+        let mut world = World::new();
+        assert!(Initialized::init_named_systems(
+            &mut world, "first", Update, sys1
+        ));
+        // the name is not part of the marker
+        assert!(!Initialized::init_named_systems(
+            &mut world, "second", Update, sys1
+        ));
+        assert!(!Initialized::init_systems(&mut world, Update, sys1));
+        assert!(Initialized::init_named_systems(
+            &mut world, "first", PostUpdate, sys1
+        ));
+        assert_eq!(Initialized::list_initialized(&world), ["first"]);
+
+        assert!(Initialized::deinit_systems(&mut world, Update, sys1));
+        assert_eq!(Initialized::list_initialized(&world), ["first"]);
+        assert!(Initialized::deinit_systems(&mut world, PostUpdate, sys1));
+        assert!(Initialized::list_initialized(&world).is_empty());
+
+        assert!(Initialized::init_named_systems(
+            &mut world, "first", Update, sys1
+        ));
+        assert_eq!(Initialized::clear_all(&mut world), 1);
+        assert!(Initialized::list_initialized(&world).is_empty());
+        assert!(Initialized::init_systems(&mut world, Update, sys1));
+    }
+
+    #[test]
+    fn test_init_with_reentrant() {
+        struct MyMarker;
+
+        fn setup(world: &mut World, depth: &mut usize) -> bool {
+            Initialized::<MyMarker>::init_with(world, |world| {
+                *depth += 1;
+                // a recursive setup doesn't run again
+                assert!(!setup(world, depth));
+                assert!(!Initialized::<MyMarker>::init(world));
+            })
+        }
+
+        let mut world = World::new();
+        let mut depth = 0;
+        assert!(setup(&mut world, &mut depth));
+        assert!(!setup(&mut world, &mut depth));
+        assert_eq!(depth, 1);
     }
 }
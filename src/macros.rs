@@ -0,0 +1,113 @@
+/// Logs at the trace level, compiled out when the `log` feature is disabled.
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "log")]
+        bevy_log::trace!($($arg)*);
+    };
+}
+
+/// Enters a span at the trace level until the end of the scope, compiled out when the `log` feature is disabled.
+macro_rules! trace_span {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "log")]
+        let _span = bevy_log::trace_span!($($arg)*).entered();
+    };
+}
+
+/// Initializes every marker in order, see [`Initialized::init`](crate::Initialized::init).
+///
+/// Returns a tuple with one `bool` per marker, `true` if that marker was not previously initialized.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_init_marker::init_markers;
+/// # use bevy::prelude::*;
+/// #
+/// # let mut world = World::new();
+/// #
+/// struct A;
+/// struct B;
+/// struct C;
+///
+/// assert_eq!(init_markers!(&mut world, A, B), (true, true));
+///
+/// match init_markers!(&mut world, A, B, C) {
+///     (_, _, true) => println!("initialized C!"),
+///     _ => unreachable!(),
+/// }
+/// ```
+#[macro_export]
+macro_rules! init_markers {
+    ($world:expr, $($marker:ty),+ $(,)?) => {{
+        let world: &mut _ = $world;
+        ($($crate::Initialized::<$marker>::init(world),)+)
+    }};
+}
+
+/// Runs the block only if the marker was not previously initialized, see [`Initialized::init`](crate::Initialized::init).
+///
+/// An optional `else` block runs if the marker was already initialized,
+/// the macro evaluates to the value of whichever block ran.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_init_marker::init_once;
+/// # use bevy::prelude::*;
+/// #
+/// # let mut world = World::new();
+/// #
+/// struct MyMarker;
+///
+/// init_once!(&mut world, MyMarker => {
+///     println!("initialized MyMarker!");
+/// });
+///
+/// let first = init_once!(&mut world, MyMarker => { true } else { false });
+/// assert!(!first);
+/// ```
+#[macro_export]
+macro_rules! init_once {
+    ($world:expr, $marker:ty => $body:block $(,)?) => {
+        if $crate::Initialized::<$marker>::init($world) $body
+    };
+    ($world:expr, $marker:ty => $body:block else $else:block $(,)?) => {
+        if $crate::Initialized::<$marker>::init($world) $body else $else
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    #[test]
+    fn test_init_markers() {
+        struct A;
+        struct B;
+
+        let mut world = World::new();
+        assert_eq!(init_markers!(&mut world, A), (true,));
+        assert_eq!(init_markers!(&mut world, A, B), (false, true));
+        assert_eq!(init_markers!(&mut world, A, B,), (false, false));
+    }
+
+    #[test]
+    fn test_init_once() {
+        struct MyMarker;
+        struct B;
+
+        let mut world = World::new();
+        let mut count = 0;
+        for _ in 0..3 {
+            init_once!(&mut world, MyMarker => {
+                count += 1;
+            });
+        }
+        assert_eq!(count, 1);
+
+        let world = &mut world;
+        assert!(!init_once!(world, MyMarker => { true } else { false }));
+        assert!(init_once!(world, B => { true } else { false }));
+    }
+}
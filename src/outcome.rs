@@ -0,0 +1,168 @@
+use bevy_ecs::{
+    schedule::{IntoSystemConfigs, ScheduleLabel, Schedules},
+    world::World,
+};
+
+use crate::Initialized;
+
+/// The outcome of [`Initialized::init_systems_outcome`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InitOutcome {
+    /// The systems were added to the schedule.
+    FreshlyAdded {
+        /// The number of systems that were added, including nested systems.
+        count: usize,
+    },
+    /// The systems had already been initialized for the schedule.
+    AlreadyInitialized,
+}
+
+impl InitOutcome {
+    /// Returns `true` if the systems were added.
+    pub fn is_freshly_added(&self) -> bool {
+        matches!(self, Self::FreshlyAdded { .. })
+    }
+}
+
+/// Whether [`Initialized::init_systems_status`] created the schedule or added to an existing one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduleStatus {
+    /// The schedule did not exist and was created.
+    ///
+    /// A newly created schedule won't run unless it's run manually or part of the main schedule order,
+    /// so this often means the schedule label is wrong.
+    Created,
+    /// The systems were added to an existing schedule.
+    Existing,
+}
+
+impl Initialized<()> {
+    /// Same as [`Initialized::init_systems`] but returns an [`InitOutcome`] with the number of added systems.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::{InitOutcome, Initialized};
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// fn sys1() {}
+    /// fn sys2() {}
+    ///
+    /// assert_eq!(
+    ///     Initialized::init_systems_outcome(&mut world, Update, (sys1, sys2)),
+    ///     InitOutcome::FreshlyAdded { count: 2 }
+    /// );
+    /// assert_eq!(
+    ///     Initialized::init_systems_outcome(&mut world, Update, (sys1, sys2)),
+    ///     InitOutcome::AlreadyInitialized
+    /// );
+    /// ```
+    #[track_caller]
+    pub fn init_systems_outcome<L, S, Marker>(
+        world: &mut World,
+        schedule: L,
+        systems: S,
+    ) -> InitOutcome
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        match Initialized::init_systems_with_id(world, schedule, systems) {
+            Some(ids) => InitOutcome::FreshlyAdded { count: ids.len() },
+            None => InitOutcome::AlreadyInitialized,
+        }
+    }
+
+    /// Same as [`Initialized::init_systems`] but returns whether the `schedule` was created.
+    ///
+    /// Returns `Some` if the systems were added, `None` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::{Initialized, ScheduleStatus};
+    /// # use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// fn sys1() {}
+    /// fn sys2() {}
+    ///
+    /// world.add_schedule(Schedule::new(Update));
+    /// assert_eq!(
+    ///     Initialized::init_systems_status(&mut world, Update, sys1),
+    ///     Some(ScheduleStatus::Existing)
+    /// );
+    ///
+    /// #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+    /// struct Typo;
+    ///
+    /// assert_eq!(
+    ///     Initialized::init_systems_status(&mut world, Typo, sys2),
+    ///     Some(ScheduleStatus::Created)
+    /// );
+    /// ```
+    #[track_caller]
+    pub fn init_systems_status<L, S, Marker>(
+        world: &mut World,
+        schedule: L,
+        systems: S,
+    ) -> Option<ScheduleStatus>
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        let exists = world
+            .get_resource::<Schedules>()
+            .is_some_and(|schedules| schedules.contains(schedule.intern()));
+        let status = if exists {
+            ScheduleStatus::Existing
+        } else {
+            ScheduleStatus::Created
+        };
+        Initialized::init_systems(world, schedule, systems).then_some(status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{InitOutcome, Initialized, ScheduleStatus};
+    use bevy::prelude::*;
+
+    #[test]
+    fn test_init_systems_outcome() {
+        fn sys1() {}
+        fn sys2() {}
+        fn sys3() {}
+
+        let mut world = World::new();
+        let outcome = Initialized::init_systems_outcome(&mut world, Update, ((sys1, sys2), sys3));
+        assert_eq!(outcome, InitOutcome::FreshlyAdded { count: 3 });
+        assert!(outcome.is_freshly_added());
+
+        let outcome = Initialized::init_systems_outcome(&mut world, Update, ((sys1, sys2), sys3));
+        assert_eq!(outcome, InitOutcome::AlreadyInitialized);
+    }
+
+    #[test]
+    fn test_init_systems_status() {
+        fn sys1() {}
+        fn sys2() {}
+
+        let mut world = World::new();
+        assert_eq!(
+            Initialized::init_systems_status(&mut world, Update, sys1),
+            Some(ScheduleStatus::Created)
+        );
+        assert_eq!(
+            Initialized::init_systems_status(&mut world, Update, sys2),
+            Some(ScheduleStatus::Existing)
+        );
+        assert_eq!(
+            Initialized::init_systems_status(&mut world, Update, sys2),
+            None
+        );
+    }
+}
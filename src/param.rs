@@ -0,0 +1,144 @@
+use std::{any::TypeId, panic::Location};
+
+use bevy_ecs::{
+    archetype::Archetype,
+    component::Tick,
+    system::{Commands, Res, SystemMeta, SystemParam},
+    world::{unsafe_world_cell::UnsafeWorldCell, World},
+};
+
+use crate::{condition::RunOnceClaims, InitRegistry, Initialized};
+
+type InitOnceState<'w, 's, M> = (
+    Option<Res<'w, Initialized<M>>>,
+    Res<'w, RunOnceClaims>,
+    Commands<'w, 's>,
+);
+
+/// A [`SystemParam`] for one-time initialization of the marker `M` in non-exclusive systems.
+///
+/// [`InitOnce::claim`] returns `true` exactly once, the [`Initialized<M>`] resource is then inserted using [`Commands`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_init_marker::InitOnce;
+/// # use bevy::prelude::*;
+/// #
+/// # let mut app = App::new();
+/// #
+/// struct MyMarker;
+///
+/// fn my_system(mut init: InitOnce<MyMarker>) {
+///     if init.claim() {
+///         // do stuff once
+///     }
+/// }
+///
+/// app.add_systems(Update, my_system);
+/// ```
+pub struct InitOnce<'w, 's, M: Send + Sync + 'static> {
+    initialized: Option<Res<'w, Initialized<M>>>,
+    claims: Res<'w, RunOnceClaims>,
+    commands: Commands<'w, 's>,
+}
+
+impl<M: Send + Sync + 'static> InitOnce<'_, '_, M> {
+    /// Returns `true` if the marker `M` has been initialized.
+    ///
+    /// Also returns `true` if this or another [`InitOnce<M>`] has claimed the marker but the commands haven't been applied yet.
+    #[must_use]
+    pub fn is_initialized(&self) -> bool {
+        self.initialized.is_some() || self.claims.is_claimed(TypeId::of::<M>())
+    }
+
+    /// Claims the marker `M`, returns `true` if it was not previously initialized or claimed.
+    ///
+    /// The marker is initialized once the commands of this system are applied.
+    #[must_use]
+    #[track_caller]
+    pub fn claim(&mut self) -> bool {
+        if self.initialized.is_some() || !self.claims.claim(TypeId::of::<M>()) {
+            return false;
+        }
+        let location = Location::caller();
+        self.commands.add(move |world: &mut World| {
+            // unclaim first, `init` treats a remaining claim as already initialized
+            world.resource::<RunOnceClaims>().unclaim(TypeId::of::<M>());
+            if Initialized::<M>::init(world) {
+                InitRegistry::relocate::<M>(world, location);
+            }
+        });
+        true
+    }
+}
+
+// SAFETY: Access is the same as `InitOnceState`.
+unsafe impl<M: Send + Sync + 'static> SystemParam for InitOnce<'_, '_, M> {
+    type State = <InitOnceState<'static, 'static, M> as SystemParam>::State;
+    type Item<'w, 's> = InitOnce<'w, 's, M>;
+
+    fn init_state(world: &mut World, system_meta: &mut SystemMeta) -> Self::State {
+        world.init_resource::<RunOnceClaims>();
+        InitOnceState::<M>::init_state(world, system_meta)
+    }
+
+    fn new_archetype(state: &mut Self::State, archetype: &Archetype, system_meta: &mut SystemMeta) {
+        InitOnceState::<M>::new_archetype(state, archetype, system_meta);
+    }
+
+    fn apply(state: &mut Self::State, system_meta: &SystemMeta, world: &mut World) {
+        InitOnceState::<M>::apply(state, system_meta, world);
+    }
+
+    unsafe fn get_param<'w, 's>(
+        state: &'s mut Self::State,
+        system_meta: &SystemMeta,
+        world: UnsafeWorldCell<'w>,
+        change_tick: Tick,
+    ) -> Self::Item<'w, 's> {
+        // SAFETY: `InitOnceState::get_param` has the same invariants as `Self::get_param`.
+        let (initialized, claims, commands) =
+            InitOnceState::<M>::get_param(state, system_meta, world, change_tick);
+        InitOnce {
+            initialized,
+            claims,
+            commands,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{InitOnce, Initialized};
+    use bevy::prelude::*;
+
+    #[derive(Resource, Default)]
+    struct Counter(usize);
+
+    struct MyMarker;
+
+    fn count(mut init: InitOnce<MyMarker>, mut counter: ResMut<Counter>) {
+        if init.claim() {
+            counter.0 += 1;
+        }
+    }
+
+    #[test]
+    fn test_init_once() {
+        let mut app = App::new();
+        app.init_resource::<Counter>()
+            .add_systems(Update, (count, count, count));
+
+        for _ in 0..10 {
+            app.update();
+        }
+        assert_eq!(app.world.resource::<Counter>().0, 1);
+        assert!(Initialized::<MyMarker>::is_initialized(&app.world));
+
+        // can be claimed again after deinit
+        assert!(Initialized::<MyMarker>::deinit(&mut app.world));
+        app.update();
+        assert_eq!(app.world.resource::<Counter>().0, 2);
+    }
+}
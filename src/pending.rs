@@ -0,0 +1,200 @@
+use std::{any::TypeId, sync::Mutex};
+
+use bevy_ecs::{system::Resource, world::World};
+
+use crate::Initialized;
+
+/// A queued one-time setup, see [`PendingInits`].
+struct PendingInit {
+    marker: TypeId,
+    init: fn(&mut World) -> bool,
+    setup: Box<dyn FnOnce(&mut World) + Send>,
+}
+
+/// A [`Resource`] of one-time setups requested with [`InitMarkerCommandsExt::init_pending`](crate::InitMarkerCommandsExt::init_pending).
+///
+/// The setups run when [`apply_pending_inits`] runs, so requesting a setup is decoupled from applying it.
+/// Each setup is guarded by its marker, so it runs at most once even if it's requested many times.
+#[derive(Resource, Default)]
+pub struct PendingInits(Mutex<Vec<PendingInit>>);
+
+impl PendingInits {
+    /// Queues the `setup` for the marker `M` unless it's already queued.
+    ///
+    /// Returns `true` if the setup was queued, `false` otherwise.
+    pub(crate) fn push<M: Send + Sync + 'static>(
+        &self,
+        setup: impl FnOnce(&mut World) + Send + 'static,
+    ) -> bool {
+        let mut pending = self.0.lock().unwrap();
+        let marker = TypeId::of::<M>();
+        if pending.iter().any(|init| init.marker == marker) {
+            return false;
+        }
+        pending.push(PendingInit {
+            marker,
+            init: Initialized::<M>::init,
+            setup: Box::new(setup),
+        });
+        true
+    }
+
+    /// Returns the number of queued setups.
+    pub fn len(&self) -> usize {
+        self.0.lock().unwrap().len()
+    }
+
+    /// Returns `true` if no setups are queued.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// An exclusive system that initializes the markers queued in [`PendingInits`] and runs their setups.
+///
+/// Setups whose marker has already been initialized are dropped without running.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_init_marker::{apply_pending_inits, InitMarkerCommandsExt};
+/// # use bevy::prelude::*;
+/// #
+/// # let mut app = App::new();
+/// #
+/// struct MyMarker;
+///
+/// fn request(mut commands: Commands) {
+///     commands.init_pending::<MyMarker>(|world| {
+///         // do stuff once
+///     });
+/// }
+///
+/// app.add_systems(Update, request)
+///     .add_systems(PostUpdate, apply_pending_inits);
+/// ```
+pub fn apply_pending_inits(world: &mut World) {
+    let Some(mut pending) = world.get_resource_mut::<PendingInits>() else {
+        return;
+    };
+    let pending = std::mem::take(pending.0.get_mut().unwrap());
+    for PendingInit { init, setup, .. } in pending {
+        if init(world) {
+            setup(world);
+        }
+    }
+}
+
+/// Returns an exclusive system that runs at most `max_per_run` of the setups queued in [`PendingInits`] every time it runs.
+///
+/// Spreads the setups over several frames to avoid a hitch, the setups run in the order they were requested.
+/// Setups whose marker has already been initialized are dropped without counting towards the budget.
+///
+/// See [`apply_pending_inits`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_init_marker::apply_pending_inits_budgeted;
+/// # use bevy::prelude::*;
+/// #
+/// # let mut app = App::new();
+/// #
+/// app.add_systems(PostUpdate, apply_pending_inits_budgeted(2));
+/// ```
+pub fn apply_pending_inits_budgeted(max_per_run: usize) -> impl FnMut(&mut World) {
+    move |world: &mut World| {
+        let mut ran = 0;
+        while ran < max_per_run {
+            let Some(mut pending) = world.get_resource_mut::<PendingInits>() else {
+                return;
+            };
+            let pending = pending.0.get_mut().unwrap();
+            if pending.is_empty() {
+                return;
+            }
+            let PendingInit { init, setup, .. } = pending.remove(0);
+            if init(world) {
+                setup(world);
+                ran += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        apply_pending_inits, apply_pending_inits_budgeted, InitMarkerCommandsExt, Initialized,
+        PendingInits,
+    };
+    use bevy::{ecs::system::RunSystemOnce, prelude::*};
+
+    #[derive(Resource, Default)]
+    struct Counter(usize);
+
+    #[test]
+    fn test_apply_pending_inits() {
+        struct MyMarker;
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+
+        let request = |mut commands: Commands| {
+            for _ in 0..3 {
+                commands.init_pending::<MyMarker>(|world| {
+                    world.resource_mut::<Counter>().0 += 1;
+                });
+            }
+        };
+
+        world.run_system_once(request);
+        // requested but not applied yet
+        assert_eq!(world.resource::<PendingInits>().len(), 1);
+        assert!(!Initialized::<MyMarker>::is_initialized(&world));
+
+        world.run_system_once(apply_pending_inits);
+        assert!(world.resource::<PendingInits>().is_empty());
+        assert!(Initialized::<MyMarker>::is_initialized(&world));
+
+        // already initialized markers are not queued
+        world.run_system_once(request);
+        assert!(world.resource::<PendingInits>().is_empty());
+        world.run_system_once(apply_pending_inits);
+        assert_eq!(world.resource::<Counter>().0, 1);
+    }
+
+    #[test]
+    fn test_apply_pending_inits_budgeted() {
+        struct A;
+        struct B;
+        struct C;
+        struct D;
+        struct E;
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+
+        world.run_system_once(|mut commands: Commands| {
+            commands.init_pending::<A>(|world| world.resource_mut::<Counter>().0 += 1);
+            commands.init_pending::<B>(|world| world.resource_mut::<Counter>().0 += 1);
+            commands.init_pending::<C>(|world| world.resource_mut::<Counter>().0 += 1);
+            commands.init_pending::<D>(|world| world.resource_mut::<Counter>().0 += 1);
+            commands.init_pending::<E>(|world| world.resource_mut::<Counter>().0 += 1);
+        });
+        assert_eq!(world.resource::<PendingInits>().len(), 5);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(apply_pending_inits_budgeted(1));
+        for frame in 1..=5 {
+            schedule.run(&mut world);
+            assert_eq!(world.resource::<Counter>().0, frame);
+            assert_eq!(world.resource::<PendingInits>().len(), 5 - frame);
+        }
+        assert!(Initialized::<E>::is_initialized(&world));
+
+        // every setup ran once
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 5);
+    }
+}
@@ -0,0 +1,94 @@
+use bevy_app::{App, Plugin};
+
+use crate::InitRegistry;
+#[cfg(feature = "reflect")]
+use crate::Initialized;
+
+/// Sets up the resources used by the optional features of this crate.
+///
+/// - Inserts the [`InitRegistry`].
+/// - Registers `Initialized<()>` for reflection with the `reflect` feature.
+/// - Adds the [`MarkerInitialized`](crate::MarkerInitialized) event with the `events` feature.
+/// - Inserts the [`InitStats`](crate::InitStats) with the `stats` feature.
+///
+/// The plugin is optional, the registry and stats are otherwise inserted by the first [`Initialized::init`](crate::Initialized::init)
+/// and no events are sent, but with the plugin they can be accessed by systems right away.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_init_marker::{InitMarkerPlugin, InitRegistry};
+/// # use bevy::prelude::*;
+/// #
+/// # let mut app = App::new();
+/// #
+/// fn list_markers(registry: Res<InitRegistry>) {
+///     for entry in registry.iter() {
+///         println!("{}", entry.type_name());
+///     }
+/// }
+///
+/// app.add_plugins(InitMarkerPlugin)
+///     .add_systems(Update, list_markers);
+/// ```
+#[derive(Default)]
+pub struct InitMarkerPlugin;
+
+impl Plugin for InitMarkerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InitRegistry>();
+        #[cfg(feature = "reflect")]
+        app.register_type::<Initialized<()>>();
+        #[cfg(feature = "events")]
+        app.add_event::<crate::MarkerInitialized>();
+        #[cfg(feature = "stats")]
+        app.init_resource::<crate::InitStats>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{InitMarkerPlugin, InitRegistry, Initialized};
+    use bevy::prelude::*;
+
+    #[test]
+    fn test_init_marker_plugin() {
+        struct MyMarker;
+
+        let mut app = App::new();
+        app.add_plugins(InitMarkerPlugin);
+        assert!(app.world.resource::<InitRegistry>().is_empty());
+
+        #[cfg(feature = "reflect")]
+        assert!(app
+            .world
+            .resource::<AppTypeRegistry>()
+            .read()
+            .get(std::any::TypeId::of::<Initialized<()>>())
+            .is_some());
+
+        assert!(Initialized::<MyMarker>::init(&mut app.world));
+        assert!(app.world.resource::<InitRegistry>().contains::<MyMarker>());
+
+        #[cfg(feature = "events")]
+        {
+            let events = app.world.resource::<Events<crate::MarkerInitialized>>();
+            assert_eq!(events.get_reader().read(events).count(), 1);
+        }
+
+        #[cfg(feature = "stats")]
+        assert!(app.world.contains_resource::<crate::InitStats>());
+    }
+
+    #[test]
+    fn test_without_plugin() {
+        struct MyMarker;
+
+        let mut world = World::new();
+        assert!(Initialized::<MyMarker>::init(&mut world));
+        assert!(world.resource::<InitRegistry>().contains::<MyMarker>());
+
+        #[cfg(feature = "events")]
+        assert!(!world.contains_resource::<Events<crate::MarkerInitialized>>());
+    }
+}
@@ -0,0 +1,156 @@
+use std::any::TypeId;
+
+use bevy_ecs::{system::Resource, world::World};
+use bevy_utils::HashMap;
+
+use crate::Initialized;
+
+/// The reference counts of the markers initialized with [`Initialized::init_ref`].
+#[derive(Resource, Default)]
+pub(crate) struct InitRefCounts(HashMap<TypeId, RefCount>);
+
+/// The references to a single marker.
+#[derive(Default)]
+struct RefCount {
+    count: usize,
+    /// `true` if the marker was initialized by [`Initialized::init_ref`], only then the last reference deinitializes it.
+    owned: bool,
+}
+
+impl<M: Send + Sync + 'static> Initialized<M> {
+    /// Acquires a reference to the `Initialized<M>` resource, initializing it if it isn't initialized.
+    ///
+    /// Returns `true` if the resource was initialized by this call, `false` otherwise.
+    ///
+    /// The marker stays initialized until every reference is released with [`Initialized::deinit_ref`].
+    /// If the marker is deinitialized by other means, like [`Initialized::deinit`], the next reference initializes it again.
+    /// A marker that was already initialized before the first reference, like with [`Initialized::init`],
+    /// is left initialized when the last reference is released.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// struct SharedSetup;
+    ///
+    /// // two subsystems require the shared setup
+    /// assert!(Initialized::<SharedSetup>::init_ref(&mut world));
+    /// assert!(!Initialized::<SharedSetup>::init_ref(&mut world));
+    ///
+    /// assert!(!Initialized::<SharedSetup>::deinit_ref(&mut world));
+    /// assert!(Initialized::<SharedSetup>::is_initialized(&world));
+    ///
+    /// // the last release deinitializes the marker
+    /// assert!(Initialized::<SharedSetup>::deinit_ref(&mut world));
+    /// assert!(!Initialized::<SharedSetup>::is_initialized(&world));
+    /// ```
+    #[track_caller]
+    pub fn init_ref(world: &mut World) -> bool {
+        let missing = !Self::is_initialized(world);
+        let initialized = missing && Self::init(world);
+        let mut counts = world.get_resource_or_insert_with(InitRefCounts::default);
+        let count = counts.0.entry(TypeId::of::<M>()).or_default();
+        count.count += 1;
+        count.owned |= missing;
+        initialized
+    }
+
+    /// Releases a reference acquired with [`Initialized::init_ref`], deinitializing the marker for the last reference.
+    ///
+    /// Returns `true` if the resource was deinitialized by this call, `false` otherwise.
+    /// Does nothing if there are no references to release.
+    /// The marker is only deinitialized if it was initialized by [`Initialized::init_ref`].
+    pub fn deinit_ref(world: &mut World) -> bool {
+        let Some(mut counts) = world.get_resource_mut::<InitRefCounts>() else {
+            return false;
+        };
+        let Some(count) = counts.0.get_mut(&TypeId::of::<M>()) else {
+            return false;
+        };
+        count.count -= 1;
+        if count.count == 0 {
+            let owned = count.owned;
+            counts.0.remove(&TypeId::of::<M>());
+            return owned && Self::deinit(world);
+        }
+        false
+    }
+
+    /// Returns the number of references acquired with [`Initialized::init_ref`] that haven't been released yet.
+    #[must_use]
+    pub fn ref_count(world: &World) -> usize {
+        world
+            .get_resource::<InitRefCounts>()
+            .and_then(|counts| counts.0.get(&TypeId::of::<M>()))
+            .map(|count| count.count)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Initialized;
+    use bevy::prelude::*;
+
+    #[test]
+    fn test_init_ref() {
+        struct MyMarker;
+
+        let mut world = World::new();
+        assert!(!Initialized::<MyMarker>::deinit_ref(&mut world));
+
+        for _ in 0..2 {
+            assert!(Initialized::<MyMarker>::init_ref(&mut world));
+            assert!(!Initialized::<MyMarker>::init_ref(&mut world));
+            assert!(!Initialized::<MyMarker>::init_ref(&mut world));
+            assert_eq!(Initialized::<MyMarker>::ref_count(&world), 3);
+
+            assert!(!Initialized::<MyMarker>::deinit_ref(&mut world));
+            assert!(!Initialized::<MyMarker>::deinit_ref(&mut world));
+            assert!(Initialized::<MyMarker>::is_initialized(&world));
+
+            assert!(Initialized::<MyMarker>::deinit_ref(&mut world));
+            assert!(!Initialized::<MyMarker>::is_initialized(&world));
+            assert_eq!(Initialized::<MyMarker>::ref_count(&world), 0);
+        }
+
+        // releasing more than acquired does nothing
+        assert!(!Initialized::<MyMarker>::deinit_ref(&mut world));
+    }
+
+    #[test]
+    fn test_init_ref_after_deinit() {
+        struct MyMarker;
+
+        let mut world = World::new();
+        assert!(Initialized::<MyMarker>::init_ref(&mut world));
+        assert!(Initialized::<MyMarker>::deinit(&mut world));
+
+        // the next reference initializes the marker again
+        assert!(Initialized::<MyMarker>::init_ref(&mut world));
+        assert!(Initialized::<MyMarker>::is_initialized(&world));
+        assert_eq!(Initialized::<MyMarker>::ref_count(&world), 2);
+
+        assert!(!Initialized::<MyMarker>::deinit_ref(&mut world));
+        assert!(Initialized::<MyMarker>::deinit_ref(&mut world));
+        assert!(!Initialized::<MyMarker>::is_initialized(&world));
+    }
+
+    #[test]
+    fn test_init_ref_existing() {
+        struct MyMarker;
+
+        let mut world = World::new();
+        assert!(Initialized::<MyMarker>::init(&mut world));
+
+        assert!(!Initialized::<MyMarker>::init_ref(&mut world));
+        assert!(!Initialized::<MyMarker>::deinit_ref(&mut world));
+
+        // the marker wasn't initialized by `init_ref`
+        assert!(Initialized::<MyMarker>::is_initialized(&world));
+    }
+}
@@ -0,0 +1,698 @@
+use std::{any::TypeId, panic::Location};
+
+use bevy_ecs::{component::ComponentId, system::Resource, world::World};
+
+use crate::{
+    clear_boxed_keys, condition::RunOnceClaims, dedup, refcount::InitRefCounts, remove_systems,
+    Initialized, InitializedLabels,
+};
+
+/// A [`Resource`] that records every [`Initialized`] marker in the world.
+///
+/// Inserted by [`Initialized::init`] the first time a marker is initialized.
+#[derive(Resource, Debug, Default)]
+pub struct InitRegistry {
+    entries: Vec<InitEntry>,
+    next_sequence: u64,
+}
+
+/// An entry in the [`InitRegistry`].
+#[derive(Debug, Clone)]
+pub struct InitEntry {
+    type_id: TypeId,
+    type_name: &'static str,
+    sequence: u64,
+    location: &'static Location<'static>,
+    component_id: Option<ComponentId>,
+    deinit: fn(&mut World) -> bool,
+}
+
+impl InitEntry {
+    /// The [`TypeId`] of the marker, see [`Initialized::marker_type_id`].
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// The type name of the marker, or the name given to [`Initialized::init_named_systems`].
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The order in which the marker was initialized, starting at `0` for the first marker in the world.
+    ///
+    /// The sequence is shared by every marker and keeps increasing when markers are deinitialized.
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    /// The source location of the call that initialized the marker.
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+
+    /// The [`ComponentId`] of the `Initialized<M>` resource, `None` for markers that aren't stored as a resource,
+    /// like the ones of [`Initialized::init_named_systems`].
+    pub fn component_id(&self) -> Option<ComponentId> {
+        self.component_id
+    }
+}
+
+impl InitRegistry {
+    /// Returns an iterator over the entries in the order they were initialized.
+    pub fn iter(&self) -> impl Iterator<Item = &InitEntry> {
+        self.entries.iter()
+    }
+
+    /// Returns the number of initialized markers.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no markers are initialized.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns `true` if the marker `M` is in the registry.
+    pub fn contains<M: 'static>(&self) -> bool {
+        self.get::<M>().is_some()
+    }
+
+    /// Returns the entry for the marker `M`.
+    pub fn get<M: 'static>(&self) -> Option<&InitEntry> {
+        self.get_by_type_id(TypeId::of::<M>())
+    }
+
+    /// Returns the entry for the marker with the given [`TypeId`].
+    pub fn get_by_type_id(&self, type_id: TypeId) -> Option<&InitEntry> {
+        self.entries.iter().find(|entry| entry.type_id == type_id)
+    }
+
+    /// Returns the entry for the marker whose resource has the given [`ComponentId`].
+    ///
+    /// Useful for tools that iterate the resources of a world to tell which ones are markers.
+    pub fn get_by_component_id(&self, component_id: ComponentId) -> Option<&InitEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.component_id == Some(component_id))
+    }
+
+    /// Records the marker `M`, inserting the registry if it doesn't exist.
+    pub(crate) fn record<M: Send + Sync + 'static>(
+        world: &mut World,
+        location: &'static Location<'static>,
+    ) {
+        let component_id = world.components().resource_id::<Initialized<M>>();
+        Self::record_named(
+            world,
+            TypeId::of::<M>(),
+            std::any::type_name::<M>(),
+            location,
+            component_id,
+            Initialized::<M>::deinit,
+        );
+    }
+
+    /// Records a marker with the `type_id` under the given `name`, inserting the registry if it doesn't exist.
+    pub(crate) fn record_named(
+        world: &mut World,
+        type_id: TypeId,
+        name: &'static str,
+        location: &'static Location<'static>,
+        component_id: Option<ComponentId>,
+        deinit: fn(&mut World) -> bool,
+    ) {
+        let mut registry = world.get_resource_or_insert_with(Self::default);
+        let sequence = registry.next_sequence;
+        registry.next_sequence += 1;
+        registry.entries.push(InitEntry {
+            type_id,
+            type_name: name,
+            sequence,
+            location,
+            component_id,
+            deinit,
+        });
+    }
+
+    /// Overrides the location of the marker `M`, for initializations deferred through commands.
+    pub(crate) fn relocate<M: 'static>(world: &mut World, location: &'static Location<'static>) {
+        let Some(mut registry) = world.get_resource_mut::<Self>() else {
+            return;
+        };
+        let type_id = TypeId::of::<M>();
+        if let Some(entry) = registry
+            .entries
+            .iter_mut()
+            .find(|entry| entry.type_id == type_id)
+        {
+            entry.location = location;
+        }
+    }
+
+    /// Overrides the function that deinitializes the marker `M`, for markers that also own systems.
+    pub(crate) fn set_deinit<M: 'static>(world: &mut World, deinit: fn(&mut World) -> bool) {
+        let Some(mut registry) = world.get_resource_mut::<Self>() else {
+            return;
+        };
+        let type_id = TypeId::of::<M>();
+        if let Some(entry) = registry
+            .entries
+            .iter_mut()
+            .find(|entry| entry.type_id == type_id)
+        {
+            entry.deinit = deinit;
+        }
+    }
+
+    /// Removes the marker `M` from the registry.
+    pub(crate) fn forget<M: 'static>(world: &mut World) {
+        Self::forget_type_id(world, TypeId::of::<M>());
+    }
+
+    /// Removes the marker with the `type_id` from the registry.
+    pub(crate) fn forget_type_id(world: &mut World, type_id: TypeId) {
+        if let Some(mut registry) = world.get_resource_mut::<Self>() {
+            registry.entries.retain(|entry| entry.type_id != type_id);
+        }
+    }
+}
+
+impl<M: Send + Sync + 'static> Initialized<M> {
+    /// Returns the [`TypeId`] of the marker `M`, not of `Initialized<M>`.
+    ///
+    /// This is the same id stored in the [`InitRegistry`], see [`InitEntry::type_id`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::any::TypeId;
+    /// # use bevy_init_marker::{InitRegistry, Initialized};
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// struct MyMarker;
+    ///
+    /// let _ = Initialized::<MyMarker>::init(&mut world);
+    ///
+    /// let type_id = Initialized::<MyMarker>::marker_type_id();
+    /// assert_eq!(type_id, TypeId::of::<MyMarker>());
+    /// assert!(world.resource::<InitRegistry>().get_by_type_id(type_id).is_some());
+    /// ```
+    #[must_use]
+    pub fn marker_type_id() -> TypeId {
+        TypeId::of::<M>()
+    }
+
+    /// Returns the order in which `Initialized<M>` was initialized, `None` if it's not initialized.
+    ///
+    /// See [`InitEntry::sequence`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// struct A;
+    /// struct B;
+    ///
+    /// let _ = Initialized::<A>::init(&mut world);
+    /// let _ = Initialized::<B>::init(&mut world);
+    ///
+    /// assert_eq!(Initialized::<A>::init_sequence(&world), Some(0));
+    /// assert_eq!(Initialized::<B>::init_sequence(&world), Some(1));
+    /// ```
+    #[must_use]
+    pub fn init_sequence(world: &World) -> Option<u64> {
+        world
+            .get_resource::<InitRegistry>()
+            .and_then(|registry| registry.get::<M>())
+            .map(InitEntry::sequence)
+    }
+
+    /// Returns the source location of the call that initialized `Initialized<M>`, `None` if it's not initialized.
+    ///
+    /// Useful to find out which code initialized a marker when two subsystems unexpectedly share it.
+    /// Initializations deferred through [`Commands`](bevy_ecs::system::Commands) record the location of the command.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// struct MyMarker;
+    ///
+    /// let _ = Initialized::<MyMarker>::init(&mut world);
+    ///
+    /// let location = Initialized::<MyMarker>::init_location(&world).unwrap();
+    /// println!("`MyMarker` was initialized at {location}");
+    /// ```
+    #[must_use]
+    pub fn init_location(world: &World) -> Option<&'static Location<'static>> {
+        world
+            .get_resource::<InitRegistry>()
+            .and_then(|registry| registry.get::<M>())
+            .map(InitEntry::location)
+    }
+
+    /// Returns the [`ComponentId`] of the `Initialized<M>` resource, `None` if it's not initialized.
+    ///
+    /// See [`InitRegistry::get_by_component_id`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::{InitRegistry, Initialized};
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// struct MyMarker;
+    ///
+    /// let _ = Initialized::<MyMarker>::init(&mut world);
+    ///
+    /// let component_id = Initialized::<MyMarker>::component_id(&world).unwrap();
+    /// assert!(world.get_resource_by_id(component_id).is_some());
+    /// assert!(world.resource::<InitRegistry>().get_by_component_id(component_id).is_some());
+    /// ```
+    #[must_use]
+    pub fn component_id(world: &World) -> Option<ComponentId> {
+        world
+            .get_resource::<InitRegistry>()
+            .and_then(|registry| registry.get::<M>())
+            .and_then(InitEntry::component_id)
+    }
+}
+
+impl Initialized<()> {
+    /// Returns the type names of every initialized marker in the order they were initialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// struct MyMarker;
+    ///
+    /// let _ = Initialized::<MyMarker>::init(&mut world);
+    ///
+    /// assert_eq!(
+    ///     Initialized::list_initialized(&world),
+    ///     [std::any::type_name::<MyMarker>()]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn list_initialized(world: &World) -> Vec<&'static str> {
+        world
+            .get_resource::<InitRegistry>()
+            .map(|registry| registry.iter().map(InitEntry::type_name).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the type names in `expected` that haven't been initialized yet, in the same order.
+    ///
+    /// The names are compared with [`std::any::type_name`] of the markers recorded in the [`InitRegistry`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// struct A;
+    /// struct B;
+    ///
+    /// let expected = [std::any::type_name::<A>(), std::any::type_name::<B>()];
+    ///
+    /// let _ = Initialized::<A>::init(&mut world);
+    ///
+    /// assert_eq!(Initialized::missing(&world, &expected), [std::any::type_name::<B>()]);
+    /// ```
+    #[must_use]
+    pub fn missing(world: &World, expected: &[&'static str]) -> Vec<&'static str> {
+        let registry = world.get_resource::<InitRegistry>();
+        expected
+            .iter()
+            .copied()
+            .filter(|name| {
+                !registry
+                    .is_some_and(|registry| registry.iter().any(|entry| entry.type_name == *name))
+            })
+            .collect()
+    }
+}
+
+impl Initialized<()> {
+    /// Deinitializes every marker in the [`InitRegistry`], along with the markers of systems, sets and settings initialized for a schedule.
+    ///
+    /// Returns the number of markers that were removed.
+    ///
+    /// Initializing everything again behaves like in a fresh world:
+    /// - the systems added with [`Initialized::init_systems`], [`dedup_by`](crate::dedup_by)
+    ///   and [`Initialized::init_systems_boxed`] are disabled like [`Initialized::deinit_systems`] does,
+    /// - the counts of [`Initialized::init_ref`] are reset,
+    /// - the markers claimed by [`run_once`](crate::run_once) conditions are released.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// struct A;
+    /// struct B;
+    ///
+    /// let _ = Initialized::<A>::init(&mut world);
+    /// let _ = Initialized::<B>::init(&mut world);
+    ///
+    /// assert_eq!(Initialized::clear_all(&mut world), 2);
+    /// assert!(!Initialized::<A>::is_initialized(&world));
+    /// ```
+    pub fn clear_all(world: &mut World) -> usize {
+        world.remove_resource::<InitRefCounts>();
+        if let Some(claims) = world.get_resource::<RunOnceClaims>() {
+            claims.clear();
+        }
+        let keys: Vec<_> = world
+            .get_resource_mut::<InitializedLabels>()
+            .map(|mut labels| labels.keys.drain().collect())
+            .unwrap_or_default();
+        for (key, schedule) in &keys {
+            remove_systems(world, *key, *schedule);
+        }
+        let labeled = keys.len() + dedup::clear_hashes(world) + clear_boxed_keys(world);
+        let Some(mut registry) = world.get_resource_mut::<InitRegistry>() else {
+            return labeled;
+        };
+        let entries = std::mem::take(&mut registry.entries);
+        labeled
+            + entries
+                .into_iter()
+                .filter(|entry| (entry.deinit)(world))
+                .count()
+    }
+
+    /// Deinitializes every marker in the [`InitRegistry`] whose type name matches the `predicate`.
+    ///
+    /// Returns the number of markers that were removed.
+    ///
+    /// This is useful to re-initialize the markers of a single module, like everything under `my_game::ui::`.
+    /// Markers of systems, sets and settings initialized for a schedule are not in the registry and are never removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// mod ui {
+    ///     pub struct Menu;
+    ///     pub struct Hud;
+    /// }
+    ///
+    /// struct Physics;
+    ///
+    /// let _ = Initialized::<ui::Menu>::init(&mut world);
+    /// let _ = Initialized::<ui::Hud>::init(&mut world);
+    /// let _ = Initialized::<Physics>::init(&mut world);
+    ///
+    /// assert_eq!(Initialized::clear_matching(&mut world, |name| name.contains("::ui::")), 2);
+    /// assert!(!Initialized::<ui::Menu>::is_initialized(&world));
+    /// assert!(Initialized::<Physics>::is_initialized(&world));
+    /// ```
+    pub fn clear_matching(world: &mut World, predicate: impl Fn(&str) -> bool) -> usize {
+        let Some(registry) = world.get_resource::<InitRegistry>() else {
+            return 0;
+        };
+        let matching: Vec<_> = registry
+            .entries
+            .iter()
+            .filter(|entry| predicate(entry.type_name))
+            .map(|entry| entry.deinit)
+            .collect();
+        matching.into_iter().filter(|deinit| deinit(world)).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use crate::{run_once, InitRegistry, Initialized};
+    use bevy::prelude::*;
+
+    #[test]
+    fn test_list_initialized() {
+        struct A;
+        struct B;
+
+        let mut world = World::new();
+        assert!(Initialized::list_initialized(&world).is_empty());
+
+        assert!(Initialized::<A>::init(&mut world));
+        assert!(Initialized::<B>::init(&mut world));
+        assert!(!Initialized::<A>::init(&mut world));
+        assert_eq!(
+            Initialized::list_initialized(&world),
+            [std::any::type_name::<A>(), std::any::type_name::<B>()]
+        );
+
+        assert!(Initialized::<A>::deinit(&mut world));
+        assert_eq!(
+            Initialized::list_initialized(&world),
+            [std::any::type_name::<B>()]
+        );
+        assert!(!world.resource::<InitRegistry>().contains::<A>());
+    }
+
+    #[test]
+    fn test_clear_all() {
+        struct A;
+
+        fn sys1() {}
+
+        let mut world = World::new();
+        assert_eq!(Initialized::clear_all(&mut world), 0);
+
+        assert!(Initialized::<A>::init(&mut world));
+        assert!(Initialized::init_systems(&mut world, Update, sys1));
+        assert_eq!(Initialized::clear_all(&mut world), 2);
+        assert!(world.resource::<InitRegistry>().is_empty());
+
+        assert!(Initialized::<A>::init(&mut world));
+        assert!(Initialized::init_systems(&mut world, Update, sys1));
+    }
+
+    #[test]
+    fn test_clear_all_fresh() {
+        struct A;
+
+        #[derive(Resource, Default)]
+        struct Counter(usize);
+
+        fn count(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+
+        assert!(Initialized::<A>::init_ref(&mut world));
+        assert!(Initialized::init_systems(&mut world, Update, count));
+        Initialized::clear_all(&mut world);
+        assert_eq!(Initialized::<A>::ref_count(&world), 0);
+
+        // the systems are only run once after initializing them again
+        assert!(Initialized::<A>::init_ref(&mut world));
+        assert!(Initialized::init_systems(&mut world, Update, count));
+        world.run_schedule(Update);
+        assert_eq!(world.resource::<Counter>().0, 1);
+        assert_eq!(Initialized::<A>::ref_count(&world), 1);
+    }
+
+    #[test]
+    fn test_clear_all_run_once() {
+        struct MyMarker;
+
+        let mut app = App::new();
+        app.add_systems(Update, (|| {}).run_if(run_once::<MyMarker>()));
+        app.update();
+
+        // the claim of the condition is released
+        Initialized::clear_all(&mut app.world);
+        assert!(Initialized::<MyMarker>::init(&mut app.world));
+    }
+
+    #[test]
+    fn test_clear_all_resource_systems() {
+        #[derive(Resource, Default)]
+        struct Counter(usize);
+
+        fn count(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        let mut world = World::new();
+        assert!(Initialized::init_resource_systems::<Counter, _, _, _>(
+            &mut world, Update, count
+        ));
+        assert_eq!(Initialized::clear_all(&mut world), 1);
+
+        assert!(Initialized::init_resource_systems::<Counter, _, _, _>(
+            &mut world, Update, count
+        ));
+        world.run_schedule(Update);
+        assert_eq!(world.resource::<Counter>().0, 1);
+    }
+
+    #[test]
+    fn test_component_id() {
+        #[derive(Resource)]
+        struct NotAMarker;
+
+        struct A;
+
+        fn sys1() {}
+
+        let mut world = World::new();
+        world.insert_resource(NotAMarker);
+        assert!(Initialized::<A>::component_id(&world).is_none());
+
+        assert!(Initialized::<A>::init(&mut world));
+        Initialized::init_named_systems(&mut world, "sys1", Update, sys1);
+
+        let component_id = Initialized::<A>::component_id(&world).unwrap();
+        assert_eq!(
+            Some(component_id),
+            world.components().resource_id::<Initialized<A>>()
+        );
+
+        // filter the resources of the world down to the markers
+        let registry = world.resource::<InitRegistry>();
+        let markers: Vec<_> = world
+            .storages()
+            .resources
+            .iter()
+            .filter_map(|(id, _)| registry.get_by_component_id(id))
+            .map(|entry| entry.type_id())
+            .collect();
+        assert_eq!(markers, [TypeId::of::<A>()]);
+
+        assert!(Initialized::<A>::deinit(&mut world));
+        assert!(Initialized::<A>::component_id(&world).is_none());
+    }
+
+    #[test]
+    fn test_missing() {
+        struct A;
+        struct B;
+
+        let expected = [std::any::type_name::<A>(), std::any::type_name::<B>()];
+
+        let mut world = World::new();
+        assert_eq!(Initialized::missing(&world, &expected), expected);
+
+        assert!(Initialized::<B>::init(&mut world));
+        assert_eq!(
+            Initialized::missing(&world, &expected),
+            [std::any::type_name::<A>()]
+        );
+
+        assert!(Initialized::<A>::init(&mut world));
+        assert!(Initialized::missing(&world, &expected).is_empty());
+    }
+
+    #[test]
+    fn test_init_sequence() {
+        struct A;
+        struct B;
+
+        let mut world = World::new();
+        assert_eq!(Initialized::<A>::init_sequence(&world), None);
+
+        assert!(Initialized::<A>::init(&mut world));
+        assert!(Initialized::<B>::init(&mut world));
+        assert!(!Initialized::<A>::init(&mut world));
+        assert_eq!(Initialized::<A>::init_sequence(&world), Some(0));
+        assert_eq!(Initialized::<B>::init_sequence(&world), Some(1));
+
+        assert!(Initialized::<A>::deinit(&mut world));
+        assert_eq!(Initialized::<A>::init_sequence(&world), None);
+        assert!(Initialized::<A>::init(&mut world));
+        assert_eq!(Initialized::<A>::init_sequence(&world), Some(2));
+    }
+
+    #[test]
+    fn test_marker_type_id() {
+        struct A;
+        struct B;
+
+        let mut world = World::new();
+        assert!(Initialized::<A>::init(&mut world));
+
+        let registry = world.resource::<InitRegistry>();
+        let entry = registry
+            .get_by_type_id(Initialized::<A>::marker_type_id())
+            .unwrap();
+        assert_eq!(entry.type_id(), TypeId::of::<A>());
+        assert_eq!(entry.type_name(), std::any::type_name::<A>());
+        assert!(registry
+            .get_by_type_id(Initialized::<B>::marker_type_id())
+            .is_none());
+    }
+
+    #[test]
+    fn test_clear_matching() {
+        struct A;
+        struct B;
+
+        let mut world = World::new();
+        assert_eq!(Initialized::clear_matching(&mut world, |_| true), 0);
+
+        assert!(Initialized::<A>::init(&mut world));
+        assert!(Initialized::<B>::init(&mut world));
+        let a = std::any::type_name::<A>();
+        assert_eq!(Initialized::clear_matching(&mut world, |name| name == a), 1);
+        assert!(!Initialized::<A>::is_initialized(&world));
+        assert!(Initialized::<B>::is_initialized(&world));
+        assert_eq!(
+            Initialized::list_initialized(&world),
+            [std::any::type_name::<B>()]
+        );
+
+        // can be initialized again
+        assert!(Initialized::<A>::init(&mut world));
+    }
+
+    #[test]
+    fn test_init_location() {
+        struct A;
+
+        let mut world = World::new();
+        assert_eq!(Initialized::<A>::init_location(&world), None);
+
+        let line = line!() + 1;
+        assert!(Initialized::<A>::init(&mut world));
+        assert!(!Initialized::<A>::init(&mut world));
+
+        let location = Initialized::<A>::init_location(&world).unwrap();
+        assert_eq!(location.file(), file!());
+        assert_eq!(location.line(), line);
+    }
+}
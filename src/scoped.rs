@@ -0,0 +1,114 @@
+use bevy_ecs::{system::Resource, world::World};
+
+use crate::Initialized;
+
+/// The markers initialized by [`Initialized::init_scoped`] along with the resource that governs them.
+#[derive(Resource, Default)]
+struct ScopedMarkers(Vec<ScopedMarker>);
+
+struct ScopedMarker {
+    is_alive: fn(&World) -> bool,
+    deinit: fn(&mut World) -> bool,
+}
+
+impl<M: Send + Sync + 'static> Initialized<M> {
+    /// Initializes the `Initialized<M>` resource if it hasn't been initialized yet, tying it to the lifetime of the resource `R`.
+    ///
+    /// Returns `true` if the resource was not previously initialized, `false` otherwise.
+    ///
+    /// Once `R` is removed the marker is deinitialized by [`clear_orphaned_markers`],
+    /// so re-adding `R` and calling this again initializes the marker again.
+    /// The marker is only cleared when [`clear_orphaned_markers`] runs, so it must be added to a schedule.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::{clear_orphaned_markers, Initialized};
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut app = App::new();
+    /// #
+    /// #[derive(Resource)]
+    /// struct Level;
+    ///
+    /// struct LevelSetup;
+    ///
+    /// app.add_systems(First, clear_orphaned_markers);
+    ///
+    /// app.insert_resource(Level);
+    /// assert!(Initialized::<LevelSetup>::init_scoped::<Level>(&mut app.world));
+    ///
+    /// app.world.remove_resource::<Level>();
+    /// app.update();
+    ///
+    /// app.insert_resource(Level);
+    /// assert!(Initialized::<LevelSetup>::init_scoped::<Level>(&mut app.world));
+    /// ```
+    #[must_use]
+    #[track_caller]
+    pub fn init_scoped<R: Resource>(world: &mut World) -> bool {
+        if Self::init(world) {
+            world
+                .get_resource_or_insert_with(ScopedMarkers::default)
+                .0
+                .push(ScopedMarker {
+                    is_alive: World::contains_resource::<R>,
+                    deinit: Self::deinit,
+                });
+            return true;
+        }
+        false
+    }
+}
+
+/// A system that deinitializes the markers initialized by [`Initialized::init_scoped`] whose resource has been removed.
+///
+/// Add this to a schedule that runs before the markers are initialized again, such as `First`.
+pub fn clear_orphaned_markers(world: &mut World) {
+    let Some(mut scoped) = world.get_resource_mut::<ScopedMarkers>() else {
+        return;
+    };
+    let (alive, orphaned) = std::mem::take(&mut scoped.0)
+        .into_iter()
+        .partition::<Vec<_>, _>(|marker| (marker.is_alive)(world));
+    world.resource_mut::<ScopedMarkers>().0 = alive;
+
+    for marker in orphaned {
+        (marker.deinit)(world);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{clear_orphaned_markers, Initialized};
+    use bevy::prelude::*;
+
+    #[derive(Resource)]
+    struct MyResource;
+
+    #[test]
+    fn test_init_scoped() {
+        struct MyMarker;
+
+        let mut app = App::new();
+        app.add_systems(First, clear_orphaned_markers)
+            .insert_resource(MyResource);
+
+        assert!(Initialized::<MyMarker>::init_scoped::<MyResource>(
+            &mut app.world
+        ));
+        app.update();
+        assert!(!Initialized::<MyMarker>::init_scoped::<MyResource>(
+            &mut app.world
+        ));
+
+        app.world.remove_resource::<MyResource>();
+        app.update();
+        assert!(!Initialized::<MyMarker>::is_initialized(&app.world));
+
+        app.insert_resource(MyResource);
+        assert!(Initialized::<MyMarker>::init_scoped::<MyResource>(
+            &mut app.world
+        ));
+    }
+}
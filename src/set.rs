@@ -0,0 +1,96 @@
+use std::any::TypeId;
+
+use bevy_ecs::{system::Resource, world::World};
+use bevy_utils::HashSet;
+
+/// A single [`Resource`] that stores every marker initialized through it.
+///
+/// Unlike [`Initialized`](crate::Initialized), which inserts a resource for every marker,
+/// this stores the [`TypeId`] of the markers in a shared set.
+/// This is cheaper when there are thousands of markers, but the markers don't show up as resources and aren't recorded
+/// in the [`InitRegistry`](crate::InitRegistry).
+///
+/// # Example
+///
+/// ```
+/// # use bevy_init_marker::InitializedSet;
+/// # use bevy::prelude::*;
+/// #
+/// # let mut world = World::new();
+/// #
+/// struct MyMarker;
+///
+/// if InitializedSet::init::<MyMarker>(&mut world) {
+///     // do stuff once
+/// }
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct InitializedSet(HashSet<TypeId>);
+
+impl InitializedSet {
+    /// Initializes the marker `M` if it hasn't been initialized yet.
+    ///
+    /// Returns `true` if the marker was not previously initialized, `false` otherwise.
+    #[must_use]
+    pub fn init<M: 'static>(world: &mut World) -> bool {
+        let mut set = world.get_resource_or_insert_with(Self::default);
+        let inserted = set.0.insert(TypeId::of::<M>());
+        if inserted {
+            trace!("Initialized `{}` in set", std::any::type_name::<M>());
+        }
+        inserted
+    }
+
+    /// Returns `true` if the marker `M` has been initialized.
+    #[must_use]
+    pub fn is_initialized<M: 'static>(world: &World) -> bool {
+        world
+            .get_resource::<Self>()
+            .is_some_and(|set| set.0.contains(&TypeId::of::<M>()))
+    }
+
+    /// Removes the marker `M` so that the next [`InitializedSet::init`] returns `true` again.
+    ///
+    /// Returns `true` if the marker was previously initialized, `false` otherwise.
+    pub fn deinit<M: 'static>(world: &mut World) -> bool {
+        world
+            .get_resource_mut::<Self>()
+            .is_some_and(|mut set| set.0.remove(&TypeId::of::<M>()))
+    }
+
+    /// Returns the number of initialized markers.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if no markers are initialized.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Initialized, InitializedSet};
+    use bevy::prelude::*;
+
+    #[test]
+    fn test_initialized_set() {
+        struct A;
+        struct B;
+
+        let mut world = World::new();
+        assert!(!InitializedSet::is_initialized::<A>(&world));
+        assert!(InitializedSet::init::<A>(&mut world));
+        assert!(!InitializedSet::init::<A>(&mut world));
+        assert!(InitializedSet::init::<B>(&mut world));
+        assert_eq!(world.resource::<InitializedSet>().len(), 2);
+
+        // separate from the per-type markers
+        assert!(!Initialized::<A>::is_initialized(&world));
+
+        assert!(InitializedSet::deinit::<A>(&mut world));
+        assert!(!InitializedSet::deinit::<A>(&mut world));
+        assert!(InitializedSet::init::<A>(&mut world));
+    }
+}
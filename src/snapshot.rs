@@ -0,0 +1,136 @@
+use bevy_app::App;
+use bevy_ecs::{system::Resource, world::World};
+use bevy_utils::HashMap;
+
+use crate::{InitRegistry, Initialized};
+
+/// The initialized markers at the time of [`InitRegistry::snapshot`], identified by their type names.
+///
+/// Serializable with the serde feature so it can be stored in save games and restored with [`Initialized::restore_from`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InitSnapshot {
+    markers: Vec<String>,
+}
+
+impl InitSnapshot {
+    /// Returns an iterator over the type names of the markers in the order they were initialized.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.markers.iter().map(String::as_str)
+    }
+}
+
+/// The markers registered by [`Initialized::register_for_restore`], keyed by their type names.
+#[derive(Resource, Default)]
+struct RestoreRegistry(HashMap<&'static str, fn(&mut World) -> bool>);
+
+impl InitRegistry {
+    /// Returns a snapshot of every initialized marker.
+    pub fn snapshot(&self) -> InitSnapshot {
+        InitSnapshot {
+            markers: self
+                .iter()
+                .map(|entry| entry.type_name().to_owned())
+                .collect(),
+        }
+    }
+}
+
+impl<M: Send + Sync + 'static> Initialized<M> {
+    /// Registers the marker `M` so it can be restored by [`Initialized::restore_from`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::{InitRegistry, Initialized};
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut app = App::new();
+    /// #
+    /// struct MyMarker;
+    ///
+    /// Initialized::<MyMarker>::register_for_restore(&mut app);
+    ///
+    /// let _ = Initialized::<MyMarker>::init(&mut app.world);
+    /// let snapshot = app.world.resource::<InitRegistry>().snapshot();
+    ///
+    /// // load a save game
+    /// Initialized::clear_all(&mut app.world);
+    /// assert_eq!(Initialized::restore_from(&mut app.world, &snapshot), 1);
+    /// assert!(Initialized::<MyMarker>::is_initialized(&app.world));
+    /// ```
+    pub fn register_for_restore(app: &mut App) {
+        app.world
+            .get_resource_or_insert_with(RestoreRegistry::default)
+            .0
+            .insert(std::any::type_name::<M>(), Self::init);
+    }
+}
+
+impl Initialized<()> {
+    /// Initializes every marker in the `snapshot` that was registered with [`Initialized::register_for_restore`].
+    ///
+    /// Returns the number of markers that were not previously initialized.
+    ///
+    /// Markers that weren't registered are skipped, since a type can't be recovered from its name.
+    pub fn restore_from(world: &mut World, snapshot: &InitSnapshot) -> usize {
+        let Some(registry) = world.get_resource::<RestoreRegistry>() else {
+            return 0;
+        };
+        let inits: Vec<_> = snapshot
+            .iter()
+            .filter_map(|name| {
+                let init = registry.0.get(name).copied();
+                if init.is_none() {
+                    trace!("Skipped restoring `{}` since it's not registered", name);
+                }
+                init
+            })
+            .collect();
+        inits.into_iter().filter(|init| init(world)).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{InitRegistry, Initialized};
+    use bevy::prelude::*;
+
+    #[test]
+    fn test_restore_from() {
+        struct A;
+        struct B;
+        struct C;
+
+        let mut app = App::new();
+        Initialized::<A>::register_for_restore(&mut app);
+        Initialized::<B>::register_for_restore(&mut app);
+
+        assert!(Initialized::<A>::init(&mut app.world));
+        assert!(Initialized::<C>::init(&mut app.world));
+        let snapshot = app.world.resource::<InitRegistry>().snapshot();
+        assert_eq!(snapshot.iter().count(), 2);
+
+        assert_eq!(Initialized::clear_all(&mut app.world), 2);
+        assert_eq!(Initialized::restore_from(&mut app.world, &snapshot), 1);
+        assert!(Initialized::<A>::is_initialized(&app.world));
+        assert!(!Initialized::<B>::is_initialized(&app.world));
+        assert!(!Initialized::<C>::is_initialized(&app.world));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_snapshot_serde() {
+        use crate::InitSnapshot;
+
+        struct A;
+
+        let mut world = World::new();
+        assert!(Initialized::<A>::init(&mut world));
+        let snapshot = world.resource::<InitRegistry>().snapshot();
+
+        let serialized = ron::to_string(&snapshot).unwrap();
+        let deserialized: InitSnapshot = ron::from_str(&serialized).unwrap();
+        assert_eq!(snapshot, deserialized);
+    }
+}
@@ -0,0 +1,334 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::{
+    schedule::{
+        InternedScheduleLabel, IntoSystemConfigs, OnEnter, OnExit, OnTransition, ScheduleLabel,
+        States,
+    },
+    system::Resource,
+    world::World,
+};
+use bevy_utils::{HashMap, HashSet};
+
+use crate::{deinit_systems_keyed, init_systems_keyed, Initialized};
+
+/// The marker of the cleanup system added by [`Initialized::init_state_scoped_systems`].
+struct StateScopedMarker<K>(PhantomData<K>);
+
+/// The schedules the systems `K` were initialized for by [`Initialized::init_state_scoped_systems`],
+/// keyed on the `OnExit` schedule that deinitializes them.
+#[derive(Resource)]
+struct StateScopedLabels<K>(
+    HashMap<InternedScheduleLabel, HashSet<InternedScheduleLabel>>,
+    PhantomData<K>,
+);
+
+impl<K> Default for StateScopedLabels<K> {
+    fn default() -> Self {
+        Self(HashMap::default(), PhantomData)
+    }
+}
+
+impl Initialized<()> {
+    /// Initialize the `systems` if they hasn't been initialized for [`OnEnter(state)`](OnEnter) yet.
+    ///
+    /// Same as [`Initialized::init_systems`] with `OnEnter(state)`, the marker includes the value of the `state`
+    /// so the same `systems` can be initialized once for every variant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+    /// enum GameState {
+    ///     #[default]
+    ///     Menu,
+    ///     InGame,
+    /// }
+    ///
+    /// fn my_system() {
+    ///     // do stuff
+    /// }
+    ///
+    /// assert!(Initialized::init_on_enter_systems(&mut world, GameState::Menu, my_system));
+    /// assert!(Initialized::init_on_enter_systems(&mut world, GameState::InGame, my_system));
+    ///
+    /// assert!(!Initialized::init_on_enter_systems(&mut world, GameState::Menu, my_system));
+    /// ```
+    #[track_caller]
+    pub fn init_on_enter_systems<S, Sys, Marker>(world: &mut World, state: S, systems: Sys) -> bool
+    where
+        S: States,
+        Sys: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        Initialized::init_systems_unchecked(world, OnEnter(state), systems)
+    }
+
+    /// Initialize the `systems` if they hasn't been initialized for [`OnExit(state)`](OnExit) yet.
+    ///
+    /// See [`Initialized::init_on_enter_systems`].
+    #[track_caller]
+    pub fn init_on_exit_systems<S, Sys, Marker>(world: &mut World, state: S, systems: Sys) -> bool
+    where
+        S: States,
+        Sys: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        Initialized::init_systems_unchecked(world, OnExit(state), systems)
+    }
+
+    /// Initialize the `systems` if they hasn't been initialized for [`OnTransition { from, to }`](OnTransition) yet.
+    ///
+    /// See [`Initialized::init_on_enter_systems`].
+    #[track_caller]
+    pub fn init_on_transition_systems<S, Sys, Marker>(
+        world: &mut World,
+        from: S,
+        to: S,
+        systems: Sys,
+    ) -> bool
+    where
+        S: States,
+        Sys: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        Initialized::init_systems_unchecked(world, OnTransition { from, to }, systems)
+    }
+
+    /// Initialize the `systems` for the `schedule` while in the `state`.
+    ///
+    /// Same as [`Initialized::init_systems`] but the systems are deinitialized with [`Initialized::deinit_systems`]
+    /// on [`OnExit(state)`](OnExit), so they stop running and are initialized again when the state is re-entered.
+    ///
+    /// The cleanup is added to `OnExit(state)` once and deinitializes the `systems` for every `schedule` they were initialized for.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut app = App::new();
+    /// #
+    /// #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+    /// enum GameState {
+    ///     #[default]
+    ///     Menu,
+    ///     InGame,
+    /// }
+    ///
+    /// fn gameplay() {
+    ///     // do stuff
+    /// }
+    ///
+    /// fn enter_game(world: &mut World) {
+    ///     // runs again every time the state is entered
+    ///     Initialized::init_state_scoped_systems(world, GameState::InGame, Update, gameplay);
+    /// }
+    ///
+    /// app.init_state::<GameState>()
+    ///     .add_systems(OnEnter(GameState::InGame), enter_game);
+    /// ```
+    #[track_caller]
+    pub fn init_state_scoped_systems<S, L, Sys, Marker>(
+        world: &mut World,
+        state: S,
+        schedule: L,
+        systems: Sys,
+    ) -> bool
+    where
+        S: States,
+        L: ScheduleLabel,
+        Sys: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        let label = schedule.intern();
+        if !Initialized::init_systems(world, label, systems) {
+            return false;
+        }
+        let on_exit = OnExit(state).intern();
+        world
+            .get_resource_or_insert_with(StateScopedLabels::<Sys>::default)
+            .0
+            .entry(on_exit)
+            .or_default()
+            .insert(label);
+        let cleanup = move |world: &mut World| {
+            let labels = world
+                .get_resource_mut::<StateScopedLabels<Sys>>()
+                .and_then(|mut labels| labels.0.remove(&on_exit))
+                .unwrap_or_default();
+            for label in labels {
+                deinit_systems_keyed::<((), Sys)>(world, label);
+            }
+        };
+        init_systems_keyed::<StateScopedMarker<Sys>, _>(world, on_exit, cleanup, false, None);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Initialized;
+    use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+
+    #[derive(States, Debug, Default, Clone, PartialEq, Eq, Hash)]
+    enum MyState {
+        #[default]
+        A,
+        B,
+    }
+
+    #[test]
+    fn test_init_state_systems() {
+        fn sys1() {}
+
+        let mut world = World::new();
+
+        assert!(Initialized::init_on_enter_systems(
+            &mut world,
+            MyState::A,
+            sys1
+        ));
+        assert!(Initialized::init_on_enter_systems(
+            &mut world,
+            MyState::B,
+            sys1
+        ));
+        assert!(Initialized::init_on_exit_systems(
+            &mut world,
+            MyState::A,
+            sys1
+        ));
+        assert!(Initialized::init_on_transition_systems(
+            &mut world,
+            MyState::A,
+            MyState::B,
+            sys1
+        ));
+
+        assert!(!Initialized::init_on_enter_systems(
+            &mut world,
+            MyState::A,
+            sys1
+        ));
+        assert!(!Initialized::init_on_enter_systems(
+            &mut world,
+            MyState::B,
+            sys1
+        ));
+        assert!(!Initialized::init_on_exit_systems(
+            &mut world,
+            MyState::A,
+            sys1
+        ));
+        assert!(!Initialized::init_on_transition_systems(
+            &mut world,
+            MyState::A,
+            MyState::B,
+            sys1
+        ));
+
+        let schedules = world.resource::<Schedules>();
+        assert!(schedules.contains(OnEnter(MyState::A)));
+        assert!(schedules.contains(OnEnter(MyState::B)));
+        assert!(schedules.contains(OnExit(MyState::A)));
+        assert!(!schedules.contains(OnExit(MyState::B)));
+    }
+
+    #[test]
+    fn test_init_state_scoped_systems() {
+        #[derive(Resource, Default)]
+        struct Counter(usize);
+
+        fn count(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        let mut app = App::new();
+        app.init_state::<MyState>().init_resource::<Counter>();
+
+        for cycle in 1..=2 {
+            // enter
+            assert!(Initialized::init_state_scoped_systems(
+                &mut app.world,
+                MyState::A,
+                Update,
+                count
+            ));
+            assert!(!Initialized::init_state_scoped_systems(
+                &mut app.world,
+                MyState::A,
+                Update,
+                count
+            ));
+            app.update();
+            assert_eq!(app.world.resource::<Counter>().0, cycle);
+
+            // exit
+            app.world
+                .resource_mut::<NextState<MyState>>()
+                .set(MyState::B);
+            app.update();
+            app.update();
+            assert_eq!(app.world.resource::<Counter>().0, cycle);
+            assert!(!Initialized::deinit_systems(&mut app.world, Update, count));
+
+            app.world
+                .resource_mut::<NextState<MyState>>()
+                .set(MyState::A);
+            app.update();
+        }
+    }
+
+    #[test]
+    fn test_init_state_scoped_systems_label_value() {
+        #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Layer(u8);
+
+        #[derive(Resource, Default)]
+        struct Counter(usize);
+
+        fn count(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        let mut app = App::new();
+        app.init_state::<MyState>().init_resource::<Counter>();
+        app.update();
+
+        assert!(Initialized::init_state_scoped_systems(
+            &mut app.world,
+            MyState::A,
+            Layer(1),
+            count
+        ));
+        assert!(Initialized::init_state_scoped_systems(
+            &mut app.world,
+            MyState::A,
+            Layer(2),
+            count
+        ));
+
+        app.world
+            .resource_mut::<NextState<MyState>>()
+            .set(MyState::B);
+        app.update();
+
+        // both values of the label are cleaned up
+        app.world.run_schedule(Layer(1));
+        app.world.run_schedule(Layer(2));
+        assert_eq!(app.world.resource::<Counter>().0, 0);
+        assert!(!Initialized::deinit_systems(
+            &mut app.world,
+            Layer(1),
+            count
+        ));
+        assert!(!Initialized::deinit_systems(
+            &mut app.world,
+            Layer(2),
+            count
+        ));
+    }
+}
@@ -0,0 +1,146 @@
+use std::any::TypeId;
+
+use bevy_ecs::{component::Tick, system::Resource, world::World};
+use bevy_utils::HashMap;
+
+use crate::Initialized;
+
+/// A [`Resource`] that counts how many times [`Initialized::init`] was called for each marker.
+///
+/// Inserted by [`Initialized::init`] the first time it's called.
+#[derive(Resource, Debug, Default)]
+pub struct InitStats {
+    markers: HashMap<TypeId, MarkerStats>,
+}
+
+/// The statistics of a single marker in the [`InitStats`].
+#[derive(Debug, Clone)]
+pub struct MarkerStats {
+    type_name: &'static str,
+    attempts: u64,
+    first_success: Option<Tick>,
+}
+
+impl MarkerStats {
+    /// The type name of the marker.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The number of times [`Initialized::init`] was called.
+    pub fn attempts(&self) -> u64 {
+        self.attempts
+    }
+
+    /// The number of times [`Initialized::init`] was called after the marker was already initialized.
+    pub fn wasted(&self) -> u64 {
+        match self.first_success {
+            Some(_) => self.attempts - 1,
+            None => self.attempts,
+        }
+    }
+
+    /// The change tick of the world when the marker was first initialized.
+    pub fn first_success(&self) -> Option<Tick> {
+        self.first_success
+    }
+}
+
+impl InitStats {
+    /// Returns the statistics for the marker `M`.
+    pub fn get<M: 'static>(&self) -> Option<&MarkerStats> {
+        self.markers.get(&TypeId::of::<M>())
+    }
+
+    /// Returns an iterator over the statistics of every marker.
+    pub fn iter(&self) -> impl Iterator<Item = &MarkerStats> {
+        self.markers.values()
+    }
+
+    /// Records an attempt to initialize the marker `M`, inserting the stats if they don't exist.
+    pub(crate) fn record<M: 'static>(world: &mut World, success: bool) {
+        let tick = world.change_tick();
+        let mut stats = world.get_resource_or_insert_with(Self::default);
+        let marker = stats
+            .markers
+            .entry(TypeId::of::<M>())
+            .or_insert_with(|| MarkerStats {
+                type_name: std::any::type_name::<M>(),
+                attempts: 0,
+                first_success: None,
+            });
+        marker.attempts += 1;
+        if success && marker.first_success.is_none() {
+            marker.first_success = Some(tick);
+        }
+    }
+}
+
+impl Initialized<()> {
+    /// Returns the [`InitStats`] if any marker has been initialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// struct MyMarker;
+    ///
+    /// for _ in 0..3 {
+    ///     let _ = Initialized::<MyMarker>::init(&mut world);
+    /// }
+    ///
+    /// let stats = Initialized::stats(&world).unwrap();
+    /// assert_eq!(stats.get::<MyMarker>().unwrap().wasted(), 2);
+    /// ```
+    #[must_use]
+    pub fn stats(world: &World) -> Option<&InitStats> {
+        world.get_resource::<InitStats>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{run_once, Initialized};
+    use bevy::prelude::*;
+
+    #[test]
+    fn test_stats() {
+        struct A;
+        struct B;
+
+        let mut world = World::new();
+        assert!(Initialized::stats(&world).is_none());
+
+        for _ in 0..5 {
+            let _ = Initialized::<A>::init(&mut world);
+        }
+        let _ = Initialized::<B>::init(&mut world);
+
+        let stats = Initialized::stats(&world).unwrap();
+        let a = stats.get::<A>().unwrap();
+        assert_eq!(a.attempts(), 5);
+        assert_eq!(a.wasted(), 4);
+        assert!(a.first_success().is_some());
+        assert_eq!(stats.get::<B>().unwrap().wasted(), 0);
+        assert_eq!(stats.iter().count(), 2);
+    }
+
+    #[test]
+    fn test_stats_after_run_once() {
+        struct MyMarker;
+
+        let mut app = App::new();
+        app.add_systems(Update, (|| {}).run_if(run_once::<MyMarker>()));
+        app.update();
+
+        assert!(!Initialized::<MyMarker>::init(&mut app.world));
+        let stats = Initialized::stats(&app.world).unwrap();
+        let marker = stats.get::<MyMarker>().unwrap();
+        assert_eq!(marker.attempts(), 1);
+        assert!(marker.first_success().is_none());
+    }
+}
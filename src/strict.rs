@@ -0,0 +1,87 @@
+use bevy_ecs::world::World;
+
+use crate::{Initialized, InitializedLabels};
+
+impl Initialized<()> {
+    /// Enables or disables strict initialization for the `world`.
+    ///
+    /// While enabled, initializing a marker that has already been initialized panics instead of returning `false`.
+    /// This applies to [`Initialized::init`] and [`Initialized::init_systems`] along with the functions built on them,
+    /// and is meant for test suites that want to catch accidental double setup.
+    ///
+    /// # Example
+    ///
+    /// ```should_panic
+    /// # use bevy_init_marker::Initialized;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// struct MyMarker;
+    ///
+    /// Initialized::set_strict(&mut world, true);
+    ///
+    /// let _ = Initialized::<MyMarker>::init(&mut world);
+    /// let _ = Initialized::<MyMarker>::init(&mut world); // panics
+    /// ```
+    pub fn set_strict(world: &mut World, strict: bool) {
+        world
+            .get_resource_or_insert_with(InitializedLabels::default)
+            .strict = strict;
+    }
+
+    /// Returns `true` if strict initialization is enabled, see [`Initialized::set_strict`].
+    #[must_use]
+    pub fn is_strict(world: &World) -> bool {
+        world
+            .get_resource::<InitializedLabels>()
+            .is_some_and(|labels| labels.strict)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Initialized;
+    use bevy::prelude::*;
+
+    #[test]
+    #[should_panic = "strict initialization"]
+    fn test_strict_init() {
+        struct MyMarker;
+
+        let mut world = World::new();
+        Initialized::set_strict(&mut world, true);
+        assert!(Initialized::is_strict(&world));
+
+        assert!(Initialized::<MyMarker>::init(&mut world));
+        let _ = Initialized::<MyMarker>::init(&mut world);
+    }
+
+    #[test]
+    #[should_panic = "strict initialization"]
+    fn test_strict_init_systems() {
+        fn sys1() {}
+
+        let mut world = World::new();
+        Initialized::set_strict(&mut world, true);
+
+        assert!(Initialized::init_systems(&mut world, Update, sys1));
+        Initialized::ensure_systems(&mut world, Update, sys1);
+    }
+
+    #[test]
+    fn test_strict_disabled() {
+        struct MyMarker;
+
+        fn sys1() {}
+
+        let mut world = World::new();
+        Initialized::set_strict(&mut world, true);
+        Initialized::set_strict(&mut world, false);
+
+        assert!(Initialized::<MyMarker>::init(&mut world));
+        assert!(!Initialized::<MyMarker>::init(&mut world));
+        assert!(Initialized::init_systems(&mut world, Update, sys1));
+        assert!(!Initialized::init_systems(&mut world, Update, sys1));
+    }
+}
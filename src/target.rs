@@ -0,0 +1,124 @@
+use bevy_ecs::world::World;
+
+use crate::Initialized;
+
+/// Storage for markers that [`Initialized::init`] and [`Initialized::deinit`] operate on.
+///
+/// Implemented for [`World`], where a marker is the `Initialized<M>` resource.
+/// Implement it for your own types to test code that initializes markers without a [`World`].
+///
+/// # Example
+///
+/// ```
+/// # use std::any::TypeId;
+/// # use bevy_init_marker::{InitTarget, Initialized};
+/// # use bevy::utils::HashSet;
+/// #
+/// #[derive(Default)]
+/// struct MockTarget(HashSet<TypeId>);
+///
+/// impl InitTarget for MockTarget {
+///     fn contains<M: Send + Sync + 'static>(&self) -> bool {
+///         self.0.contains(&TypeId::of::<M>())
+///     }
+///
+///     fn insert<M: Send + Sync + 'static>(&mut self) -> bool {
+///         self.0.insert(TypeId::of::<M>())
+///     }
+///
+///     fn remove<M: Send + Sync + 'static>(&mut self) -> bool {
+///         self.0.remove(&TypeId::of::<M>())
+///     }
+/// }
+///
+/// struct MyMarker;
+///
+/// fn setup(target: &mut impl InitTarget) -> bool {
+///     Initialized::<MyMarker>::init(target)
+/// }
+///
+/// let mut target = MockTarget::default();
+/// assert!(setup(&mut target));
+/// assert!(!setup(&mut target));
+/// ```
+pub trait InitTarget {
+    /// Returns `true` if the marker `M` is initialized.
+    fn contains<M: Send + Sync + 'static>(&self) -> bool;
+
+    /// Initializes the marker `M`.
+    ///
+    /// Returns `true` if the marker was not previously initialized, `false` otherwise.
+    #[track_caller]
+    fn insert<M: Send + Sync + 'static>(&mut self) -> bool;
+
+    /// Deinitializes the marker `M`.
+    ///
+    /// Returns `true` if the marker was previously initialized, `false` otherwise.
+    fn remove<M: Send + Sync + 'static>(&mut self) -> bool;
+}
+
+impl InitTarget for World {
+    fn contains<M: Send + Sync + 'static>(&self) -> bool {
+        Initialized::<M>::is_initialized(self)
+    }
+
+    #[track_caller]
+    fn insert<M: Send + Sync + 'static>(&mut self) -> bool {
+        Initialized::<M>::init_inner(self, true)
+    }
+
+    fn remove<M: Send + Sync + 'static>(&mut self) -> bool {
+        Initialized::<M>::deinit_inner(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::TypeId;
+
+    use crate::{InitTarget, Initialized};
+    use bevy::{prelude::*, utils::HashSet};
+
+    #[derive(Default)]
+    struct MockTarget(HashSet<TypeId>);
+
+    impl InitTarget for MockTarget {
+        fn contains<M: Send + Sync + 'static>(&self) -> bool {
+            self.0.contains(&TypeId::of::<M>())
+        }
+
+        fn insert<M: Send + Sync + 'static>(&mut self) -> bool {
+            self.0.insert(TypeId::of::<M>())
+        }
+
+        fn remove<M: Send + Sync + 'static>(&mut self) -> bool {
+            self.0.remove(&TypeId::of::<M>())
+        }
+    }
+
+    #[test]
+    fn test_mock_target() {
+        struct MyMarker;
+
+        let mut target = MockTarget::default();
+        assert!(Initialized::<MyMarker>::init(&mut target));
+        assert!(!Initialized::<MyMarker>::init(&mut target));
+        assert!(target.contains::<MyMarker>());
+
+        assert!(Initialized::<MyMarker>::deinit(&mut target));
+        assert!(!Initialized::<MyMarker>::deinit(&mut target));
+        assert!(!target.contains::<MyMarker>());
+    }
+
+    #[test]
+    fn test_world_target() {
+        struct MyMarker;
+
+        let mut world = World::new();
+        assert!(InitTarget::insert::<MyMarker>(&mut world));
+        assert!(Initialized::<MyMarker>::is_initialized(&world));
+        assert!(!Initialized::<MyMarker>::init(&mut world));
+        assert!(InitTarget::remove::<MyMarker>(&mut world));
+        assert!(!InitTarget::contains::<MyMarker>(&world));
+    }
+}
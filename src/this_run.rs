@@ -0,0 +1,100 @@
+use std::any::TypeId;
+
+use bevy_ecs::{
+    system::{ResMut, Resource},
+    world::World,
+};
+use bevy_utils::HashSet;
+
+use crate::Initialized;
+
+/// A [`Resource`] for markers that have been initialized during the current schedule run.
+///
+/// Unlike [`Initialized`] the markers are cleared by [`reset_per_run_markers`],
+/// which should be added to the start of the schedule.
+///
+/// Inserted by [`Initialized::init_this_run`] the first time it's called.
+#[derive(Resource, Debug, Default)]
+pub struct InitializedThisRun(HashSet<TypeId>);
+
+impl InitializedThisRun {
+    /// Returns `true` if the marker `M` has been initialized during the current run.
+    pub fn contains<M: 'static>(&self) -> bool {
+        self.0.contains(&TypeId::of::<M>())
+    }
+}
+
+impl<M: Send + Sync + 'static> Initialized<M> {
+    /// Initializes the marker `M` in [`InitializedThisRun`] if it hasn't been initialized since the last [`reset_per_run_markers`].
+    ///
+    /// Returns `true` if the marker was not previously initialized during this run, `false` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::{reset_per_run_markers, Initialized};
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut app = App::new();
+    /// #
+    /// struct FrameSetup;
+    ///
+    /// fn my_system(world: &mut World) {
+    ///     if Initialized::<FrameSetup>::init_this_run(world) {
+    ///         // do stuff once per frame
+    ///     }
+    /// }
+    ///
+    /// app.add_systems(Update, (reset_per_run_markers, (my_system, my_system)).chain());
+    /// ```
+    #[must_use]
+    pub fn init_this_run(world: &mut World) -> bool {
+        world
+            .get_resource_or_insert_with(InitializedThisRun::default)
+            .0
+            .insert(TypeId::of::<M>())
+    }
+}
+
+/// A system that clears every marker in [`InitializedThisRun`].
+///
+/// Add this to the start of the schedule so [`Initialized::init_this_run`] returns `true` again in every run.
+pub fn reset_per_run_markers(markers: Option<ResMut<InitializedThisRun>>) {
+    if let Some(mut markers) = markers {
+        markers.0.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{reset_per_run_markers, Initialized, InitializedThisRun};
+    use bevy::prelude::*;
+
+    #[derive(Resource, Default)]
+    struct Counter(usize);
+
+    #[test]
+    fn test_init_this_run() {
+        struct MyMarker;
+
+        fn count(world: &mut World) {
+            if Initialized::<MyMarker>::init_this_run(world) {
+                world.resource_mut::<Counter>().0 += 1;
+            }
+        }
+
+        let mut app = App::new();
+        app.init_resource::<Counter>()
+            .add_systems(Update, (reset_per_run_markers, (count, count)).chain());
+
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(app.world.resource::<Counter>().0, 3);
+        assert!(app
+            .world
+            .resource::<InitializedThisRun>()
+            .contains::<MyMarker>());
+        assert!(!Initialized::<MyMarker>::is_initialized(&app.world));
+    }
+}
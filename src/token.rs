@@ -0,0 +1,67 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use bevy_ecs::world::World;
+
+use crate::Initialized;
+
+/// Proof that `Initialized<M>` was initialized by the holder of the token.
+///
+/// Returned by [`Initialized::init_token`] on the first initialization only,
+/// so APIs can require it to make sure the one-time setup runs exactly once.
+///
+/// The token can't be constructed or cloned outside of this crate.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_init_marker::{InitToken, Initialized};
+/// # use bevy::prelude::*;
+/// #
+/// # let mut world = World::new();
+/// #
+/// struct MyMarker;
+///
+/// fn setup(world: &mut World, _token: InitToken<MyMarker>) {
+///     // do stuff once
+/// }
+///
+/// if let Some(token) = Initialized::<MyMarker>::init_token(&mut world) {
+///     setup(&mut world, token);
+/// }
+/// ```
+#[must_use]
+pub struct InitToken<M: Send + Sync + 'static>(PhantomData<fn() -> M>);
+
+impl<M: Send + Sync + 'static> Debug for InitToken<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "InitToken<{}>", std::any::type_name::<M>())
+    }
+}
+
+impl<M: Send + Sync + 'static> Initialized<M> {
+    /// Initializes the `Initialized<M>` resource if it hasn't been initialized yet.
+    ///
+    /// Returns an [`InitToken`] if the resource was not previously initialized, `None` otherwise.
+    ///
+    /// See [`Initialized::init`].
+    #[track_caller]
+    pub fn init_token(world: &mut World) -> Option<InitToken<M>> {
+        Self::init(world).then_some(InitToken(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Initialized;
+    use bevy::prelude::*;
+
+    #[test]
+    fn test_init_token() {
+        struct MyMarker;
+
+        let mut world = World::new();
+        assert!(Initialized::<MyMarker>::init_token(&mut world).is_some());
+        assert!(Initialized::<MyMarker>::init_token(&mut world).is_none());
+        assert!(Initialized::<MyMarker>::is_initialized(&world));
+    }
+}
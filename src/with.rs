@@ -0,0 +1,180 @@
+use std::fmt::Debug;
+
+use bevy_ecs::{system::Resource, world::World};
+
+/// A [`Resource`] for *something* that has been initialized, along with the value it was initialized with.
+///
+/// Like [`Initialized`](crate::Initialized) but stores an actual `M` instead of being a pure marker.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_init_marker::InitializedWith;
+/// # use bevy::prelude::*;
+/// #
+/// # let mut world = World::new();
+/// #
+/// struct MySettings {
+///     volume: f32,
+/// }
+///
+/// let settings = InitializedWith::init_or_insert_with(&mut world, || MySettings { volume: 0.5 });
+/// assert_eq!(settings.volume, 0.5);
+///
+/// // the existing value is returned
+/// let settings = InitializedWith::init_or_insert(&mut world, MySettings { volume: 1.0 });
+/// assert_eq!(settings.volume, 0.5);
+/// ```
+#[derive(Resource)]
+pub struct InitializedWith<M: Send + Sync + 'static>(M);
+
+impl<M: Send + Sync + 'static + Debug> Debug for InitializedWith<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("InitializedWith").field(&self.0).finish()
+    }
+}
+
+impl<M: Send + Sync + 'static> InitializedWith<M> {
+    /// Creates the resource with the `value`, `M` doesn't need to implement [`Default`].
+    pub fn new(value: M) -> Self {
+        Self(value)
+    }
+
+    /// Returns the stored value.
+    pub fn value(&self) -> &M {
+        &self.0
+    }
+
+    /// Initializes the `InitializedWith<M>` resource with the `value` if it hasn't been initialized yet.
+    ///
+    /// Returns `true` if the resource was not previously initialized, `false` otherwise.
+    /// The `value` is dropped if the resource was already initialized.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_init_marker::InitializedWith;
+    /// # use bevy::prelude::*;
+    /// #
+    /// # let mut world = World::new();
+    /// #
+    /// // no sensible default
+    /// struct ServerAddress(String);
+    ///
+    /// assert!(InitializedWith::init(&mut world, ServerAddress("localhost".into())));
+    /// assert!(!InitializedWith::init(&mut world, ServerAddress("example.com".into())));
+    ///
+    /// assert_eq!(InitializedWith::<ServerAddress>::get(&world).unwrap().0, "localhost");
+    /// ```
+    #[must_use]
+    pub fn init(world: &mut World, value: M) -> bool {
+        if world.contains_resource::<Self>() {
+            return false;
+        }
+        trace!("Initialized `{}` with a value", std::any::type_name::<M>());
+        world.insert_resource(Self::new(value));
+        true
+    }
+
+    /// Initializes the `InitializedWith<M>` resource with `M::default()` if it hasn't been initialized yet.
+    ///
+    /// Returns the stored value.
+    pub fn init_or_get(world: &mut World) -> &M
+    where
+        M: Default,
+    {
+        Self::init_or_insert_with(world, M::default)
+    }
+
+    /// Initializes the `InitializedWith<M>` resource with the `value` if it hasn't been initialized yet.
+    ///
+    /// Returns the stored value, which is not the `value` if the resource was already initialized.
+    pub fn init_or_insert(world: &mut World, value: M) -> &M {
+        Self::init_or_insert_with(world, || value)
+    }
+
+    /// Initializes the `InitializedWith<M>` resource with the result of `f` if it hasn't been initialized yet.
+    ///
+    /// Returns the stored value, `f` is not called if the resource was already initialized.
+    pub fn init_or_insert_with(world: &mut World, f: impl FnOnce() -> M) -> &M {
+        if !world.contains_resource::<Self>() {
+            let _ = Self::init(world, f());
+        }
+        world.resource::<Self>().value()
+    }
+
+    /// Returns the stored value if the `InitializedWith<M>` resource exists.
+    #[must_use]
+    pub fn get(world: &World) -> Option<&M> {
+        world
+            .get_resource::<Self>()
+            .map(|initialized| &initialized.0)
+    }
+
+    /// Returns `true` if the `InitializedWith<M>` resource exists.
+    #[must_use]
+    pub fn is_initialized(world: &World) -> bool {
+        world.contains_resource::<Self>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::InitializedWith;
+    use bevy::prelude::*;
+
+    #[test]
+    fn test_init_or_get() {
+        #[derive(Default, Debug, PartialEq)]
+        struct MyValue(u32);
+
+        let mut world = World::new();
+        assert!(InitializedWith::<MyValue>::get(&world).is_none());
+        assert_eq!(
+            InitializedWith::<MyValue>::init_or_get(&mut world),
+            &MyValue(0)
+        );
+        assert!(InitializedWith::<MyValue>::is_initialized(&world));
+    }
+
+    #[test]
+    fn test_init_or_insert() {
+        #[derive(Debug, PartialEq)]
+        struct MyValue(u32);
+
+        let mut world = World::new();
+        assert_eq!(
+            InitializedWith::init_or_insert(&mut world, MyValue(1)),
+            &MyValue(1)
+        );
+        assert_eq!(
+            InitializedWith::init_or_insert(&mut world, MyValue(2)),
+            &MyValue(1)
+        );
+        assert_eq!(
+            InitializedWith::<MyValue>::init_or_insert_with(&mut world, || unreachable!()),
+            &MyValue(1)
+        );
+        assert_eq!(InitializedWith::<MyValue>::get(&world), Some(&MyValue(1)));
+    }
+
+    #[test]
+    fn test_init_without_default() {
+        #[derive(Debug, PartialEq)]
+        struct NoDefault(&'static str);
+
+        let mut world = World::new();
+        assert!(InitializedWith::init(&mut world, NoDefault("a")));
+        assert!(!InitializedWith::init(&mut world, NoDefault("b")));
+        assert_eq!(
+            InitializedWith::<NoDefault>::get(&world),
+            Some(&NoDefault("a"))
+        );
+
+        world.insert_resource(InitializedWith::new(NoDefault("c")));
+        assert_eq!(
+            world.resource::<InitializedWith<NoDefault>>().value(),
+            &NoDefault("c")
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use bevy_ecs::{
+    schedule::{IntoSystemConfigs, ScheduleLabel},
+    world::World,
+};
+
+use crate::Initialized;
+
+/// Extension trait for [`World`] that forwards to the functions on [`Initialized`].
+///
+/// See also [`InitMarkerAppExt`](crate::InitMarkerAppExt).
+///
+/// # Example
+///
+/// ```
+/// # use bevy_init_marker::WorldInitExt;
+/// # use bevy::prelude::*;
+/// #
+/// # let mut world = World::new();
+/// #
+/// struct MyMarker;
+///
+/// fn my_system() {
+///     // do stuff
+/// }
+///
+/// world
+///     .init_once::<MyMarker>()
+///     .init_systems_once(Update, my_system);
+///
+/// assert!(world.is_initialized::<MyMarker>());
+/// ```
+pub trait WorldInitExt {
+    /// Initializes the `Initialized<M>` resource if it hasn't been initialized yet.
+    ///
+    /// See [`Initialized::init`].
+    #[track_caller]
+    fn init_once<M: Send + Sync + 'static>(&mut self) -> &mut Self;
+
+    /// Initializes the `Initialized<M>` resource if it hasn't been initialized yet.
+    ///
+    /// Returns `true` if the resource was not previously initialized, `false` otherwise.
+    ///
+    /// See [`Initialized::init`].
+    #[must_use]
+    #[track_caller]
+    fn try_init_once<M: Send + Sync + 'static>(&mut self) -> bool;
+
+    /// Returns `true` if the `Initialized<M>` resource exists.
+    ///
+    /// See [`Initialized::is_initialized`].
+    #[must_use]
+    fn is_initialized<M: Send + Sync + 'static>(&self) -> bool;
+
+    /// Initialize the `systems` if they hasn't been initialized for the `schedule` yet.
+    ///
+    /// See [`Initialized::init_systems`].
+    fn init_systems_once<L, S, Marker>(&mut self, schedule: L, systems: S) -> &mut Self
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static;
+
+    /// Initialize the `systems` if they hasn't been initialized for the `schedule` yet.
+    ///
+    /// Returns `true` if the systems were added, `false` otherwise.
+    ///
+    /// See [`Initialized::init_systems`].
+    fn try_init_systems_once<L, S, Marker>(&mut self, schedule: L, systems: S) -> bool
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static;
+}
+
+impl WorldInitExt for World {
+    #[track_caller]
+    fn init_once<M: Send + Sync + 'static>(&mut self) -> &mut Self {
+        let _ = Initialized::<M>::init(self);
+        self
+    }
+
+    #[track_caller]
+    fn try_init_once<M: Send + Sync + 'static>(&mut self) -> bool {
+        Initialized::<M>::init(self)
+    }
+
+    fn is_initialized<M: Send + Sync + 'static>(&self) -> bool {
+        Initialized::<M>::is_initialized(self)
+    }
+
+    #[track_caller]
+    fn init_systems_once<L, S, Marker>(&mut self, schedule: L, systems: S) -> &mut Self
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        Initialized::ensure_systems(self, schedule, systems);
+        self
+    }
+
+    #[track_caller]
+    fn try_init_systems_once<L, S, Marker>(&mut self, schedule: L, systems: S) -> bool
+    where
+        L: ScheduleLabel,
+        S: IntoSystemConfigs<Marker> + Send + Sync + 'static,
+    {
+        Initialized::init_systems(self, schedule, systems)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::WorldInitExt;
+    use bevy::prelude::*;
+
+    #[test]
+    fn test_world_init_once() {
+        struct MyMarker;
+
+        let mut world = World::new();
+        assert!(!world.is_initialized::<MyMarker>());
+        world.init_once::<MyMarker>();
+        assert!(world.is_initialized::<MyMarker>());
+        assert!(!world.try_init_once::<MyMarker>());
+    }
+
+    #[test]
+    fn test_world_init_systems_once() {
+        fn sys1() {}
+
+        let mut world = World::new();
+        world
+            .init_systems_once(Update, sys1)
+            .init_systems_once(Update, sys1);
+        assert!(!world.try_init_systems_once(Update, sys1));
+    }
+}